@@ -10,14 +10,21 @@ fn main() {
     // let valid_parser_code = String::from("5,6");
     // let valid_parser_code = String::from("(5,)");
     //let valid_parser_code = String::from("(5,6), _ ..");
-    let valid_parser_code = String::from("0o____01010___111.01____name");
+    // let valid_parser_code = String::from("0o____01010___111.01____name");
+    let valid_parser_code = String::from("(5, 6)\n[_, 0x7f.45]; name");
 
-    let tokens = match Lexer::new(valid_parser_code).lex() {
+    let mut lexer = Lexer::new(valid_parser_code);
+    let tokens = match lexer.lex() {
         Ok(tokens) => tokens,
         Err(error) => panic!("Lexer Error = {:?}", error),
     };
 
     println!("==== tokens ==== \n{:#?}", tokens);
 
-    let ast = Parser::new(tokens).parse();
+    let ast = match Parser::with_source_map(tokens, lexer.get_source_map().clone()).parse() {
+        Ok(ast) => ast,
+        Err(error) => panic!("Parser Error = {}", error),
+    };
+
+    println!("==== ast ==== \n{:#?}", ast);
 }
@@ -1,20 +1,408 @@
-use astro_lexer::kinds::TokenKind;
+use astro_lexer::{kinds::TokenKind, Span};
 
-// TODO: Apply heap allocation where appropriate.
+/************************* AST *************************/
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AST {
+    Program(Vec<AST>),
+    Block(Vec<AST>),
     SimpleExpr(SimpleExpr),
+    Expr(Expr),
+    TypeExpr(TypeExpr),
+    Iter(Iter),
+    Pat(Pat),
+    MatchPat(MatchPat),
+    Condition(Condition),
+    TypeDecl(TypeDecl),
+    Field(Box<Field>),
+    EnumDecl(EnumDecl),
+    Import(Import),
+    Error(Span), // Code skipped while recovering from a parser error.
     Empty,
 }
 
+/************************* EXPRESSIONS *************************/
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Binding(Binding),
+    Assign(Assign),
+    Return(Option<SimpleExpr>),
+    Yield(bool, Option<SimpleExpr>), // `yield from` if true.
+    Break(Option<SimpleExpr>),
+    Continue,
+    Fallthrough,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SimpleExpr {
     Terminal {
         kind: TokenKind,
         value: String,
     },
+    Symbol(String), // `:name`
     List(Vec<SimpleExpr>),
     Tuple(Vec<SimpleExpr>),
+    Dict(Vec<(SimpleExpr, Option<SimpleExpr>)>), // No value for shorthand `{ a }`.
+    Object(Object),
+    Compr(Box<Compr>),
+    BinaryOp {
+        op: String,
+        lhs: Box<SimpleExpr>,
+        rhs: Box<SimpleExpr>,
+    },
+    UnaryOp {
+        op: String,
+        fixity: Fixity,
+        expr: Box<SimpleExpr>,
+    },
+    TernaryOp {
+        condition: Box<SimpleExpr>,
+        consequent: Box<SimpleExpr>,
+        alternative: Box<SimpleExpr>,
+    },
+    Range(Range),
+    Spread(Box<SimpleExpr>),
+    Coeff {
+        coefficient: Box<SimpleExpr>,
+        expr: Box<SimpleExpr>,
+    },
+    Call {
+        expr: Box<SimpleExpr>,
+        vectorized: bool, // `f.(x)`
+        type_args: Vec<TypeExpr>,
+        args: Vec<CallArg>,
+    },
+    Dot {
+        expr: Box<SimpleExpr>,
+        name: String,
+    },
+    Index {
+        expr: Box<SimpleExpr>,
+        args: Vec<SimpleExpr>,
+    },
+    TupleIndex {
+        expr: Box<SimpleExpr>,
+        index: String,
+    },
+    Unwrap(Box<SimpleExpr>), // `expr!`
+    If(Box<If>),
+    Match(Box<Match>),
+    Loop(Vec<AST>),
+    For(Box<For>),
+    While(Box<While>),
+    Function(Box<FunctionDecl>),
+    Closure(Box<Closure>),
     Empty,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fixity {
+    Prefix,
+    Postfix,
+}
+
+/// `Name[T] { field, field: value }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Object {
+    pub name: String,
+    pub type_args: Vec<TypeExpr>,
+    pub fields: Vec<(String, Option<SimpleExpr>)>, // No value for shorthand `{ field }`.
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComprKind {
+    List,
+    Generator,
+    Dict,
+}
+
+/// `[expr | iter]`, `(expr | iter)` or `{ key: value | iter }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Compr {
+    pub kind: ComprKind,
+    pub expr: SimpleExpr,
+    pub value: Option<SimpleExpr>, // Only in dict comprehensions.
+    pub iter: Iter,
+}
+
+/// `var x in xs and y in ys where condition`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iter {
+    pub args: Vec<IterArg>,
+    pub guard: Option<SimpleExpr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterArg {
+    pub mutable: bool,
+    pub pat: Pat,
+    pub expr: SimpleExpr,
+}
+
+/// `start..step..end` with any part left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    pub start: Option<Box<SimpleExpr>>,
+    pub step: Option<Box<SimpleExpr>>,
+    pub end: Option<Box<SimpleExpr>>,
+}
+
+/// `name: expr` or `expr`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallArg {
+    pub name: Option<String>,
+    pub expr: SimpleExpr,
+}
+
+/************************* CONTROL FLOW *************************/
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Expr(SimpleExpr),
+    Binding {
+        mutable: bool, // `var` if true, `let` otherwise.
+        pat: Pat,
+        expr: SimpleExpr,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct If {
+    pub condition: Condition,
+    pub body: Vec<AST>,
+    pub elifs: Vec<(Condition, Vec<AST>)>,
+    pub else_body: Option<Vec<AST>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub expr: SimpleExpr,
+    pub arms: Vec<MatchArm>,
+}
+
+/// `pattern => body`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pat: MatchPat,
+    pub body: Vec<AST>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct For {
+    pub iter: Iter,
+    pub body: Vec<AST>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct While {
+    pub condition: Condition,
+    pub body: Vec<AST>,
+}
+
+/************************* PATTERNS *************************/
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatKind {
+    Tuple,
+    Dict,
+    List,
+}
+
+/// Patterns on the left-hand side of bindings and assignments, in loops and in parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pat {
+    Container(ContainerPat),
+    Object(TypeExpr, ContainerPat), // `Person(x, y)` or `Person { name }`
+    Ref(SimpleExpr),                // `x`, `*ptr.field` or `xs[0]`
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerPat {
+    pub kind: PatKind,
+    pub args: Vec<PatArg>,
+}
+
+/// `var ...name: pattern` with any part left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatArg {
+    pub mutable: bool,
+    pub rest: bool,
+    pub name: Option<String>,
+    pub pat: Option<Pat>,
+}
+
+/// Patterns in match arms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchPat {
+    Container(MatchContainerPat),
+    Object(TypeExpr, MatchContainerPat), // `Some { value }`
+    Id {
+        name: String,
+        relation: Option<(String, TypeExpr)>, // `x :: Int`
+    },
+    Literal(SimpleExpr),
+    Alt(Vec<MatchPat>), // `75 | 79`
+    Range {
+        start: SimpleExpr,
+        end: SimpleExpr,
+    }, // `75..79`
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchContainerPat {
+    pub kind: PatKind,
+    pub args: Vec<MatchPatArg>,
+}
+
+/// `var ...name: pattern` with any part left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchPatArg {
+    pub mutable: bool,
+    pub rest: bool,
+    pub name: Option<String>,
+    pub pat: Option<MatchPat>,
+}
+
+/************************* TYPES *************************/
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeExpr {
+    Name(String),
+    Dot(Box<TypeExpr>, String),            // `module.Type`
+    Generic(Box<TypeExpr>, Vec<TypeExpr>), // `List[T]`
+    Tuple(Vec<TypeExpr>),
+    Optional(Box<TypeExpr>), // `T?`
+    Errable(Box<TypeExpr>),  // `T!`
+    Union(Vec<TypeExpr>),
+    Intersection(Vec<TypeExpr>),
+    Function {
+        params: Vec<TypeExpr>,
+        ret: Box<TypeExpr>,
+        relations: Vec<TypeRelation>,
+    },
+}
+
+/// `T <: U` or `|T, U| :: V`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeRelation {
+    pub lhs: Vec<TypeExpr>,
+    pub op: String,
+    pub rhs: TypeExpr,
+}
+
+/// `T` or `T: Bound`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericParam {
+    pub name: String,
+    pub bound: Option<TypeExpr>,
+}
+
+/************************* DECLARATIONS *************************/
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindingKind {
+    Let,
+    Var,
+    Const,
+}
+
+/// `pub let pattern: Type = value`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    pub public: bool,
+    pub kind: BindingKind,
+    pub pat: Pat,
+    pub ty: Option<TypeExpr>,
+    pub value: Option<Box<AST>>,
+}
+
+/// `pattern: Type += value`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assign {
+    pub pat: Pat,
+    pub ty: Option<TypeExpr>,
+    pub op: String,
+    pub value: Box<AST>,
+}
+
+/// `pub type Name[T]: (Parent) { fields }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDecl {
+    pub public: bool,
+    pub name: String,
+    pub generics: Vec<GenericParam>,
+    pub parents: Vec<TypeExpr>,
+    pub fields: Vec<Field>,
+}
+
+/// `pub var name: Type = default`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub public: bool,
+    pub mutable: bool,
+    pub name: String,
+    pub ty: Option<TypeExpr>,
+    pub default: Option<AST>,
+}
+
+/// `pub enum Name[T] { variants }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDecl {
+    pub public: bool,
+    pub name: String,
+    pub generics: Vec<GenericParam>,
+    pub variants: Vec<EnumVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumVariant {
+    Unit(String),
+    Struct(String, Vec<(String, Option<TypeExpr>)>), // `Name { field: Type }`
+    Tuple(String, Vec<Option<TypeExpr>>),            // `Name(Type, _)`
+}
+
+/// `pub fun name[T](params) -> Type { body }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDecl {
+    pub public: bool,
+    pub name: Option<String>, // An identifier or an operator.
+    pub generics: Vec<GenericParam>,
+    pub params: Vec<Param>,
+    pub return_type: Option<TypeExpr>,
+    pub body: Vec<AST>,
+}
+
+/// `(params) => body`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Closure {
+    pub params: Vec<Param>,
+    pub body: Vec<AST>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+    Pat(ContainerPat), // A destructured parameter.
+    Named {
+        name: String,
+        ty: Option<TypeExpr>,
+        default: Option<AST>,
+        optional: bool, // `name?`
+    },
+}
+
+/// `import package: path.to.module { name, name: alias }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub package: Option<String>,
+    pub prefix: Option<String>, // `.` or `..`
+    pub path: Vec<String>,
+    pub names: ImportNames,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportNames {
+    Module,                               // No braces.
+    All,                                  // `{ ... }`
+    Names(Vec<(String, Option<String>)>), // Names with optional aliases.
+}
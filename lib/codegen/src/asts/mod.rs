@@ -3,6 +3,43 @@ pub mod asts;
 pub use self::{
     asts::{
         AST,
+        Expr,
         SimpleExpr,
+        Fixity,
+        Object,
+        ComprKind,
+        Compr,
+        Iter,
+        IterArg,
+        Range,
+        CallArg,
+        Condition,
+        If,
+        Match,
+        MatchArm,
+        For,
+        While,
+        PatKind,
+        Pat,
+        ContainerPat,
+        PatArg,
+        MatchPat,
+        MatchContainerPat,
+        MatchPatArg,
+        TypeExpr,
+        TypeRelation,
+        GenericParam,
+        BindingKind,
+        Binding,
+        Assign,
+        TypeDecl,
+        Field,
+        EnumDecl,
+        EnumVariant,
+        FunctionDecl,
+        Closure,
+        Param,
+        Import,
+        ImportNames,
     }
 };
@@ -1,4 +1,4 @@
-use crate::{ErrorKind, TokenKind};
+use crate::{ErrorKind, Span, TokenKind};
 
 /// Error from lexing.
 #[derive(Debug, Clone, PartialEq)]
@@ -6,6 +6,7 @@ pub struct LexerError {
     pub error: ErrorKind,
     pub kind: TokenKind,
     pub cursor: usize,
+    pub span: Span,
 }
 
 impl LexerError {
@@ -14,6 +15,7 @@ impl LexerError {
             error,
             cursor,
             kind,
+            span: Span::default(),
         }
     }
 }
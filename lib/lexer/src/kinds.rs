@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// The kinds of error a lexer can return
 #[derive(Debug, Clone, PartialEq)]
 pub enum ErrorKind {
@@ -31,5 +33,40 @@ pub enum TokenKind {
     StringLiteral,
     SingleLineComment,
     MultiLineComment,
+    Error,
     Empty,
 }
+
+impl fmt::Display for TokenKind {
+    /// Formats token kind as a readable name, e.g. "integer decimal literal".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TokenKind::Spaces => "spaces",
+            TokenKind::Newline => "newline",
+            TokenKind::Placeholder => "placeholder",
+            TokenKind::Identifier => "identifier",
+            TokenKind::BooleanLiteral => "boolean literal",
+            TokenKind::Keyword => "keyword",
+            TokenKind::Operator => "operator",
+            TokenKind::Punctuator => "punctuator",
+            TokenKind::Dots => "dots",
+            TokenKind::IntegerBinaryLiteral => "integer binary literal",
+            TokenKind::IntegerOctalLiteral => "integer octal literal",
+            TokenKind::IntegerHexadecimalLiteral => "integer hexadecimal literal",
+            TokenKind::IntegerDecimalLiteral => "integer decimal literal",
+            TokenKind::FloatBinaryLiteral => "float binary literal",
+            TokenKind::FloatOctalLiteral => "float octal literal",
+            TokenKind::FloatHexadecimalLiteral => "float hexadecimal literal",
+            TokenKind::FloatDecimalLiteral => "float decimal literal",
+            TokenKind::CharLiteral => "char literal",
+            TokenKind::RegexLiteral => "regex literal",
+            TokenKind::StringLiteral => "string literal",
+            TokenKind::SingleLineComment => "single-line comment",
+            TokenKind::MultiLineComment => "multi-line comment",
+            TokenKind::Error => "error",
+            TokenKind::Empty => "empty",
+        };
+
+        write!(f, "{}", name)
+    }
+}
@@ -1,7 +1,7 @@
 #[macro_use]
 use crate::macros;
 
-use crate::{ErrorKind, LexerError, TokenKind};
+use crate::{ErrorKind, LexerError, SourceMap, Span, TokenKind};
 
 /************************* TOKEN *************************/
 
@@ -11,6 +11,9 @@ pub struct Token {
     pub kind: TokenKind,
     pub token: Option<String>,
     pub cursor: usize,
+    pub span: Span,
+    // Whether the token comes right after a space. Ex. `print x` vs `3f`
+    pub space_before: bool,
 }
 
 impl Token {
@@ -19,6 +22,8 @@ impl Token {
             kind,
             token,
             cursor,
+            span: Span::default(),
+            space_before: false,
         }
     }
 }
@@ -31,6 +36,10 @@ impl Token {
 pub struct Lexer {
     // Code as a vector of characters.
     code: Vec<char>,
+    // Byte offset of each character in code, plus the length of code in bytes.
+    offsets: Vec<usize>,
+    // Maps byte offsets in code to lines and columns.
+    source_map: SourceMap,
     // Holds the position the lexer is at in the code.
     cursor: usize,
     // Supported space characters.
@@ -62,8 +71,14 @@ pub struct Lexer {
 impl Lexer {
     /// Creates a new lexer object from the code passed in.
     pub fn new(code: String) -> Self {
+        // Get the byte offset of each character.
+        let mut offsets: Vec<usize> = code.char_indices().map(|(offset, _)| offset).collect();
+        offsets.push(code.len());
+
         Self {
             code: code.chars().collect(),
+            offsets,
+            source_map: SourceMap::new(&code),
             // Cursor starts at the position of the next character to be consumed.
             cursor: 0,
             // TODO: Support certain Unicode characters.
@@ -125,6 +140,8 @@ impl Lexer {
                 String::from("in"),
                 String::from("as"),
                 String::from("mod"),
+                String::from("and"),
+                String::from("or"),
                 String::from("typeof"),
                 String::from("sizeof"),
                 String::from("super"),
@@ -132,6 +149,16 @@ impl Lexer {
         }
     }
 
+    /// Gets the source map of the code.
+    pub fn get_source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    /// Gets the span of the code between two cursor positions.
+    fn get_span(&self, start: usize, end: usize) -> Span {
+        self.source_map.get_span(self.offsets[start], self.offsets[end])
+    }
+
     /// Returns the next character in code but does not consume it.
     fn peek_char(&self, offset: Option<usize>) -> Option<char> {
         // Get offset value or set to zero if not specified.
@@ -612,9 +639,11 @@ impl Lexer {
                 }
 
             } else if character.is_some() && character.unwrap() == 'e' {
+                token.push(self.eat_char());
+
                 // Consume [-+]?
                 let sign = self.peek_char(None);
-                if character.is_some() && (character.unwrap() == '-' || character.unwrap() == '+') {
+                if sign.is_some() && (sign.unwrap() == '-' || sign.unwrap() == '+') {
                     token.push(self.eat_char());
                 }
 
@@ -692,9 +721,11 @@ impl Lexer {
                 }
 
             } else if character.is_some() && character.unwrap() == 'e' {
+                token.push(self.eat_char());
+
                 // Consume [-+]?
                 let sign = self.peek_char(None);
-                if character.is_some() && (character.unwrap() == '-' || character.unwrap() == '+') {
+                if sign.is_some() && (sign.unwrap() == '-' || sign.unwrap() == '+') {
                     token.push(self.eat_char());
                 }
 
@@ -772,9 +803,11 @@ impl Lexer {
                 }
 
             } else if character.is_some() && character.unwrap() == 'e' {
+                token.push(self.eat_char());
+
                 // Consume [-+]?
                 let sign = self.peek_char(None);
-                if character.is_some() && (character.unwrap() == '-' || character.unwrap() == '+') {
+                if sign.is_some() && (sign.unwrap() == '-' || sign.unwrap() == '+') {
                     token.push(self.eat_char());
                 }
 
@@ -808,6 +841,11 @@ impl Lexer {
         let mut token = String::from("");
         let cursor = self.cursor;
 
+        // A tuple index is not a float literal. Ex. pair.0.1
+        if self.is_tuple_index() {
+            return Err(LexerError::new(ErrorKind::CantConsume, kind, cursor));
+        }
+
         // Consume integerpart: digitdecimal ('-'* digitdecimal)*.
         let integer_part = self.integer_decimal_literal();
         if integer_part.is_ok() {
@@ -866,9 +904,11 @@ impl Lexer {
             }
 
         } else if character.is_some() && character.unwrap() == 'e' {
+            token.push(self.eat_char());
+
             // Consume [-+]?
             let sign = self.peek_char(None);
-            if character.is_some() && (character.unwrap() == '-' || character.unwrap() == '+') {
+            if sign.is_some() && (sign.unwrap() == '-' || sign.unwrap() == '+') {
                 token.push(self.eat_char());
             }
 
@@ -1013,7 +1053,9 @@ impl Lexer {
             if character.is_some() && character.unwrap() == '"' {
                 self.eat_char();
             } else {
-                token = String::new();
+                // Unlike "'", '"' can't be an operator so an unterminated string is an error.
+                self.cursor = cursor;
+                return Err(LexerError::new(ErrorKind::UnmatchedClosingCharacter, kind, cursor));
             }
         }
 
@@ -1142,10 +1184,6 @@ impl Lexer {
         let token = self.operator();
         return_on_ok_or_terminable_error!(token);
 
-        // Consume dots.
-        let token = self.dots();
-        return_on_ok_or_terminable_error!(token);
-
         // Consume float_binary_literal.
         let token = self.float_binary_literal();
         return_on_ok_or_terminable_error!(token);
@@ -1162,6 +1200,10 @@ impl Lexer {
         let token = self.float_decimal_literal();
         return_on_ok_or_terminable_error!(token);
 
+        // Consume dots. // After float literals that start with `.`
+        let token = self.dots();
+        return_on_ok_or_terminable_error!(token);
+
         // Consume punctuator. // Greedy (float literals that start with `.`)
         let token = self.punctuator();
         return_on_ok_or_terminable_error!(token);
@@ -1190,32 +1232,178 @@ impl Lexer {
         ))
     }
 
+    /// Checks if the next characters are the `.` or the digits of a tuple index.
+    /// Tuple indices come directly after an identifier, a literal or a closing bracket.
+    fn is_tuple_index(&self) -> bool {
+        // Checks if the character at the index can end the expression being indexed.
+        let ends_expression = |index: usize| {
+            let character = self.code[index];
+            self.identifier_end_char.find(character).is_some()
+                || self.digit_decimal.find(character).is_some()
+                || ")]}".find(character).is_some()
+        };
+
+        match self.peek_char(None) {
+            Some('.') => self.cursor > 0 && ends_expression(self.cursor - 1),
+            Some(_) => {
+                self.cursor > 1
+                    && self.code[self.cursor - 1] == '.'
+                    && ends_expression(self.cursor - 2)
+            }
+            None => false,
+        }
+    }
+
+    /// Checks if the character before the cursor position is a space.
+    fn is_space_before(&self, cursor: usize) -> bool {
+        cursor > 0 && self.space_char.find(self.code[cursor - 1]).is_some()
+    }
+
+    /// Checks if the next character can begin a token.
+    fn is_token_boundary(&self) -> bool {
+        match self.peek_char(None) {
+            Some(character) => {
+                self.space_char.find(character).is_some()
+                    || self.punctuator_char.find(character).is_some()
+                    || self.identifier_begin_char.find(character).is_some()
+                    || self.digit_decimal.find(character).is_some()
+                    || self.operator_char.find(character).is_some()
+                    || "\r\n.\"`".find(character).is_some()
+            }
+            None => true,
+        }
+    }
+
+    /// Skips the characters that caused a lexing error and returns them as an error token.
+    fn resynchronize(&mut self, error: &LexerError) -> Token {
+        let cursor = error.cursor;
+        let mut token = String::new();
+
+        // Get past the offending character.
+        self.cursor = cursor;
+        if self.is_inbounds(None) {
+            token.push(self.eat_char());
+        }
+
+        if error.error == ErrorKind::UnmatchedClosingCharacter {
+            // Skip to the end of the line.
+            loop {
+                let character = self.peek_char(None);
+                if character.is_some() && character.unwrap() != '\n' && character.unwrap() != '\r' {
+                    token.push(self.eat_char());
+                } else {
+                    break;
+                }
+            }
+        } else {
+            // Skip to the next character that can begin a token.
+            while !self.is_token_boundary() {
+                token.push(self.eat_char());
+            }
+        }
+
+        let mut token = Token::new(TokenKind::Error, Some(token), cursor);
+        token.span = self.get_span(cursor, self.cursor);
+        token.space_before = self.is_space_before(cursor);
+        token
+    }
+
+    /// Returns an iterator that lazily lexes the code one token at a time.
+    pub fn tokens(&mut self) -> Tokens<'_> {
+        Tokens::new(self)
+    }
+
     /// Advance through code and generate tokens based on Astro syntax.
     pub fn lex(&mut self) -> Result<Vec<Token>, LexerError> {
-        // A list of generated token.
+        self.tokens().collect()
+    }
+
+    /// Advance through code and generate tokens based on Astro syntax.
+    /// Characters that can't be lexed become error tokens and lexing continues after them.
+    pub fn lex_recovering(&mut self) -> (Vec<Token>, Vec<LexerError>) {
+        // A list of generated tokens and the errors found along the way.
         let mut tokens = vec![];
+        let mut errors = vec![];
+
+        // Restart the token iterator after every error.
+        while let Some(token) = self.tokens().next() {
+            match token {
+                Ok(token) => tokens.push(token),
+                Err(error) => {
+                    tokens.push(self.resynchronize(&error));
+                    errors.push(error);
+                }
+            }
+        }
 
-        // Iteratively advance through code and lex it.
-        while self.is_inbounds(None) {
+        (tokens, errors)
+    }
+}
+
+/************************* TOKENS *************************/
+
+/// An iterator that pulls tokens from a lexer on demand.
+/// It stops after the first lexing error.
+pub struct Tokens<'a> {
+    // The lexer to pull tokens from.
+    lexer: &'a mut Lexer,
+    // Whether lexing has stopped due to an error.
+    stopped: bool,
+}
+
+impl<'a> Tokens<'a> {
+    /// Creates a new token iterator from the lexer passed in.
+    pub fn new(lexer: &'a mut Lexer) -> Self {
+        Self {
+            lexer,
+            stopped: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Token, LexerError>;
+
+    /// Lexes and returns the next token that is not a space or a comment.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Nothing more to lex after an error.
+        if self.stopped {
+            return None;
+        }
+
+        // Advance through code until a token worth returning is lexed.
+        while self.lexer.is_inbounds(None) {
             // Lex the next set of characters.
-            let token = self.lex_next();
+            let cursor = self.lexer.cursor;
+            let token = self.lexer.lex_next();
 
             // Check for lexing error.
             if token.is_err() {
-                return Err(token.unwrap_err());
+                let mut error = token.unwrap_err();
+
+                // Error spans the offending character if there is one.
+                let end = (error.cursor + 1).min(self.lexer.code.len());
+                error.span = self.lexer.get_span(error.cursor, end);
+
+                self.stopped = true;
+                return Some(Err(error));
             }
 
-            // TODO: support multiple token returns.
+            // If there is no error, get the token value.
+            let mut token = token.unwrap();
 
-            // If there isno error, get the token value.
-            let token = token.unwrap();
+            // Token spans all the characters consumed.
+            token.span = self.lexer.get_span(cursor, self.lexer.cursor);
 
-            // Push tokens that are not spaces, ...
+            // Keep track of spaces before the token since space tokens are not returned.
+            token.space_before = self.lexer.is_space_before(cursor);
+
+            // Return tokens that are not spaces, ...
             if token.kind != TokenKind::Spaces && token.kind != TokenKind::SingleLineComment && token.kind != TokenKind::MultiLineComment {
-                tokens.push(token);
+                return Some(Ok(token));
             }
         }
 
-        Ok(tokens)
+        None
     }
 }
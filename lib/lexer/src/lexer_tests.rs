@@ -0,0 +1,321 @@
+/************************* TESTS *************************/
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, Lexer, LexerError, SourceMap, Span, Token, TokenKind};
+
+    fn get_token(kind: TokenKind, token: Option<&str>, cursor: usize, span: Span) -> Token {
+        let mut token = Token::new(kind, token.map(String::from), cursor);
+        token.span = span;
+        token
+    }
+
+    fn get_spaced_token(kind: TokenKind, token: Option<&str>, cursor: usize, span: Span) -> Token {
+        let mut token = get_token(kind, token, cursor, span);
+        token.space_before = true;
+        token
+    }
+
+    fn get_error(error: ErrorKind, kind: TokenKind, cursor: usize, span: Span) -> LexerError {
+        let mut error = LexerError::new(error, kind, cursor);
+        error.span = span;
+        error
+    }
+
+    #[test]
+    fn tokens() {
+        // Tokens are lexed on demand.
+        let lexer = &mut Lexer::new("name = 5 // comment".into());
+        let tokens_1: Vec<Result<Token, LexerError>> = lexer.tokens().take(1).collect();
+
+        // Lexing resumes where the previous iterator stopped.
+        let tokens_2: Vec<Result<Token, LexerError>> = lexer.tokens().collect();
+
+        assert_eq!(
+            tokens_1,
+            vec![Ok(get_token(
+                TokenKind::Identifier,
+                Some("name"),
+                0,
+                Span::new(0, 4, 1, 1)
+            ))]
+        );
+        assert_eq!(
+            tokens_2,
+            vec![
+                Ok(get_spaced_token(
+                    TokenKind::Operator,
+                    Some("="),
+                    5,
+                    Span::new(5, 6, 1, 6)
+                )),
+                Ok(get_spaced_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("5"),
+                    7,
+                    Span::new(7, 8, 1, 8)
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_stop_on_error() {
+        // Iteration stops after the first error.
+        let lexer = &mut Lexer::new("5 # 6".into());
+        let tokens: Vec<Result<Token, LexerError>> = lexer.tokens().collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Ok(get_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("5"),
+                    0,
+                    Span::new(0, 1, 1, 1)
+                )),
+                Err(get_error(
+                    ErrorKind::DoesNotMatchAnyRule,
+                    TokenKind::Empty,
+                    2,
+                    Span::new(2, 3, 1, 3)
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn lex() {
+        // Same tokens as the iterator.
+        let tokens_1 = Lexer::new("[1, x]\n".into()).lex();
+        let tokens_2: Result<Vec<Token>, LexerError> =
+            Lexer::new("[1, x]\n".into()).tokens().collect();
+
+        // First error is returned.
+        let tokens_3 = Lexer::new("x # y".into()).lex();
+
+        assert_eq!(tokens_1, tokens_2);
+        assert_eq!(
+            tokens_1,
+            Ok(vec![
+                get_token(TokenKind::Punctuator, Some("["), 0, Span::new(0, 1, 1, 1)),
+                get_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("1"),
+                    1,
+                    Span::new(1, 2, 1, 2)
+                ),
+                get_token(TokenKind::Punctuator, Some(","), 2, Span::new(2, 3, 1, 3)),
+                get_spaced_token(TokenKind::Identifier, Some("x"), 4, Span::new(4, 5, 1, 5)),
+                get_token(TokenKind::Punctuator, Some("]"), 5, Span::new(5, 6, 1, 6)),
+                get_token(TokenKind::Newline, None, 6, Span::new(6, 7, 1, 7)),
+            ])
+        );
+        assert_eq!(
+            tokens_3,
+            Err(get_error(
+                ErrorKind::DoesNotMatchAnyRule,
+                TokenKind::Empty,
+                2,
+                Span::new(2, 3, 1, 3)
+            ))
+        );
+    }
+
+    #[test]
+    fn spans() {
+        // Tokens on multiple lines with multibyte characters.
+        let tokens = Lexer::new("a ÷ 'é'\r\n  b".into()).lex();
+
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                get_token(TokenKind::Identifier, Some("a"), 0, Span::new(0, 1, 1, 1)),
+                get_spaced_token(TokenKind::Operator, Some("÷"), 2, Span::new(2, 4, 1, 3)),
+                get_spaced_token(
+                    TokenKind::StringLiteral,
+                    Some("é"),
+                    4,
+                    Span::new(5, 9, 1, 5)
+                ),
+                get_token(TokenKind::Newline, None, 7, Span::new(9, 11, 1, 8)),
+                get_spaced_token(
+                    TokenKind::Identifier,
+                    Some("b"),
+                    11,
+                    Span::new(13, 14, 2, 3)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn tuple_index() {
+        // Tuple indices are not float literals.
+        let tokens_1 = Lexer::new("t.0.1".into()).lex();
+
+        // Floats that don't follow an expression.
+        let tokens_2 = Lexer::new("0..0.5 .5".into()).lex();
+
+        assert_eq!(
+            tokens_1,
+            Ok(vec![
+                get_token(TokenKind::Identifier, Some("t"), 0, Span::new(0, 1, 1, 1)),
+                get_token(TokenKind::Dots, Some("."), 1, Span::new(1, 2, 1, 2)),
+                get_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("0"),
+                    2,
+                    Span::new(2, 3, 1, 3)
+                ),
+                get_token(TokenKind::Dots, Some("."), 3, Span::new(3, 4, 1, 4)),
+                get_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("1"),
+                    4,
+                    Span::new(4, 5, 1, 5)
+                ),
+            ])
+        );
+        assert_eq!(
+            tokens_2,
+            Ok(vec![
+                get_token(
+                    TokenKind::IntegerDecimalLiteral,
+                    Some("0"),
+                    0,
+                    Span::new(0, 1, 1, 1)
+                ),
+                get_token(TokenKind::Dots, Some(".."), 1, Span::new(1, 3, 1, 2)),
+                get_token(
+                    TokenKind::FloatDecimalLiteral,
+                    Some("0.5"),
+                    3,
+                    Span::new(3, 6, 1, 4)
+                ),
+                get_spaced_token(
+                    TokenKind::FloatDecimalLiteral,
+                    Some("0.5"),
+                    7,
+                    Span::new(7, 9, 1, 8)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn float_exponent() {
+        // Exponents without a fractional part.
+        let tokens = Lexer::new("1e5 2e-3 0b1e+1 0o7e2".into()).lex();
+
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                get_token(
+                    TokenKind::FloatDecimalLiteral,
+                    Some("1e5"),
+                    0,
+                    Span::new(0, 3, 1, 1)
+                ),
+                get_spaced_token(
+                    TokenKind::FloatDecimalLiteral,
+                    Some("2e-3"),
+                    4,
+                    Span::new(4, 8, 1, 5)
+                ),
+                get_spaced_token(
+                    TokenKind::FloatBinaryLiteral,
+                    Some("1e+1"),
+                    9,
+                    Span::new(9, 15, 1, 10)
+                ),
+                get_spaced_token(
+                    TokenKind::FloatOctalLiteral,
+                    Some("7e2"),
+                    16,
+                    Span::new(16, 21, 1, 17)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn source_map() {
+        let source_map = SourceMap::new("let a = 1\n\n  é = 2\r\nb");
+
+        assert_eq!(source_map.get_line_count(), 4);
+        assert_eq!(source_map.get_line_column(0), (1, 1));
+        assert_eq!(source_map.get_line_column(4), (1, 5));
+        assert_eq!(source_map.get_line_column(10), (2, 1));
+        assert_eq!(source_map.get_line_column(15), (3, 4));
+        assert_eq!(source_map.get_line_column(21), (4, 1));
+        assert_eq!(source_map.get_span(13, 15), Span::new(13, 15, 3, 3));
+        assert_eq!(source_map.get_line(1), Some("let a = 1"));
+        assert_eq!(source_map.get_line(2), Some(""));
+        assert_eq!(source_map.get_line(3), Some("  é = 2"));
+        assert_eq!(source_map.get_line(4), Some("b"));
+        assert_eq!(source_map.get_line(5), None);
+    }
+
+    #[test]
+    fn lex_recovering() {
+        // Stray characters.
+        let (tokens_1, errors_1) = Lexer::new("a #$ b €€c".into()).lex_recovering();
+
+        // Unterminated string literal.
+        let (tokens_2, errors_2) = Lexer::new("x = \"abc\ny".into()).lex_recovering();
+
+        assert_eq!(
+            tokens_1,
+            vec![
+                get_token(TokenKind::Identifier, Some("a"), 0, Span::new(0, 1, 1, 1)),
+                get_spaced_token(TokenKind::Error, Some("#"), 2, Span::new(2, 3, 1, 3)),
+                get_token(TokenKind::Punctuator, Some("$"), 3, Span::new(3, 4, 1, 4)),
+                get_spaced_token(TokenKind::Identifier, Some("b"), 5, Span::new(5, 6, 1, 6)),
+                get_spaced_token(TokenKind::Error, Some("€€"), 7, Span::new(7, 13, 1, 8)),
+                get_token(
+                    TokenKind::Identifier,
+                    Some("c"),
+                    9,
+                    Span::new(13, 14, 1, 10)
+                ),
+            ]
+        );
+        assert_eq!(
+            errors_1,
+            vec![
+                get_error(
+                    ErrorKind::DoesNotMatchAnyRule,
+                    TokenKind::Empty,
+                    2,
+                    Span::new(2, 3, 1, 3)
+                ),
+                get_error(
+                    ErrorKind::DoesNotMatchAnyRule,
+                    TokenKind::Empty,
+                    7,
+                    Span::new(7, 10, 1, 8)
+                ),
+            ]
+        );
+        assert_eq!(
+            tokens_2,
+            vec![
+                get_token(TokenKind::Identifier, Some("x"), 0, Span::new(0, 1, 1, 1)),
+                get_spaced_token(TokenKind::Operator, Some("="), 2, Span::new(2, 3, 1, 3)),
+                get_spaced_token(TokenKind::Error, Some("\"abc"), 4, Span::new(4, 8, 1, 5)),
+                get_token(TokenKind::Newline, None, 8, Span::new(8, 9, 1, 9)),
+                get_token(TokenKind::Identifier, Some("y"), 9, Span::new(9, 10, 2, 1)),
+            ]
+        );
+        assert_eq!(
+            errors_2,
+            vec![get_error(
+                ErrorKind::UnmatchedClosingCharacter,
+                TokenKind::StringLiteral,
+                4,
+                Span::new(4, 5, 1, 5)
+            )]
+        );
+    }
+}
@@ -3,10 +3,14 @@ pub mod macros;
 pub mod errors;
 pub mod kinds;
 pub mod lexer;
+pub mod span;
 
+#[cfg(test)]
+mod lexer_tests;
 
 pub use self::{
     errors::LexerError,
     kinds::{ErrorKind, TokenKind},
-    lexer::{Lexer, Token},
+    lexer::{Lexer, Token, Tokens},
+    span::{SourceMap, Span},
 };
@@ -0,0 +1,102 @@
+/************************* SPAN *************************/
+
+/// A region of code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    // Byte offset of the first character.
+    pub start: usize,
+    // Byte offset after the last character.
+    pub end: usize,
+    // Line the region starts on, starting from 1.
+    pub line: usize,
+    // Column the region starts on, starting from 1.
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize, line: usize, column: usize) -> Self {
+        Self {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+}
+
+/************************* SOURCE MAP *************************/
+
+/// Maps byte offsets in code back to lines and columns.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    // The code being mapped.
+    code: String,
+    // Byte offsets at which each line starts.
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    /// Creates a new source map from the code passed in.
+    pub fn new(code: &str) -> Self {
+        // The first line starts at the beginning of the code.
+        let mut line_starts = vec![0];
+
+        // Every other line starts after a '\n'.
+        for (offset, character) in code.char_indices() {
+            if character == '\n' {
+                line_starts.push(offset + 1);
+            }
+        }
+
+        Self {
+            code: code.into(),
+            line_starts,
+        }
+    }
+
+    /// Gets the number of lines in the code.
+    pub fn get_line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Gets the line and column of a byte offset.
+    pub fn get_line_column(&self, offset: usize) -> (usize, usize) {
+        // Clamp offset to the end of the code.
+        let offset = offset.min(self.code.len());
+
+        // Find the last line that starts at or before the offset.
+        let index = match self.line_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+
+        // Count the characters between the start of the line and the offset.
+        let line_start = self.line_starts[index];
+        let column = self.code[line_start..offset].chars().count();
+
+        (index + 1, column + 1)
+    }
+
+    /// Gets the span of the code between two byte offsets.
+    pub fn get_span(&self, start: usize, end: usize) -> Span {
+        let (line, column) = self.get_line_column(start);
+        Span::new(start, end, line, column)
+    }
+
+    /// Gets the content of a line without its line ending.
+    pub fn get_line(&self, line: usize) -> Option<&str> {
+        // Lines start from 1.
+        if line == 0 || line > self.line_starts.len() {
+            return None;
+        }
+
+        // Get the byte range of the line.
+        let start = self.line_starts[line - 1];
+        let end = match self.line_starts.get(line) {
+            Some(next_line_start) => next_line_start - 1,
+            None => self.code.len(),
+        };
+
+        Some(self.code[start..end].trim_end_matches('\r'))
+    }
+}
@@ -1,7 +1,7 @@
-use astro_lexer::Token;
-use std::{collections::HashMap, fmt::Debug};
+use astro_lexer::{SourceMap, Span, Token};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug};
 
-use crate::{errors::ParserError, kinds::ErrorKind, macros, utils::get_func_addr};
+use crate::{errors::ParserError, kinds::ErrorKind, macros};
 
 /************************* CACHE DATA *************************/
 
@@ -11,25 +11,81 @@ use crate::{errors::ParserError, kinds::ErrorKind, macros, utils::get_func_addr}
 struct CacheData<T> {
     data: Result<Output<T>, ParserError>,
     skip: usize,
+    // Whether the data is a left recursion seed, i.e. the rule is still being parsed.
+    is_seed: bool,
+    // Whether the rule called itself at the same cursor position while being parsed.
+    is_left_recursive: bool,
+    // The expectations the rule recorded and the cursor position they were recorded at.
+    expected: (usize, Vec<String>),
 }
 
 impl<T> CacheData<T> {
     fn new(data: Result<Output<T>, ParserError>, skip: usize) -> Self {
-        Self { data, skip }
+        Self {
+            data,
+            skip,
+            is_seed: false,
+            is_left_recursive: false,
+            expected: (0, vec![]),
+        }
     }
 }
 
 /************************* COMBINATOR ARGUMENT *************************/
 
+/// Identifies a parser rule.
+pub type RuleId = &'static str;
+
+/// Identifies a combinator function, e.g. `alt`.
+pub type FuncId = &'static str;
+
+/// Uniquely identifies a parser rule applied to its arguments in the cache,
+/// e.g. `rule` or `rule("a", other_rule)`.
+pub type RuleKey = Cow<'static, str>;
+
+/// A combinator or parser function.
+pub type CombinatorFn<'a, T> =
+    fn(&[CombinatorArg<'a, T>], &mut Combinator<T>) -> Result<Output<T>, ParserError>;
+
 /// The types of arguments a combinator function can take
 pub enum CombinatorArg<'a, T> {
-    Func(
-        (
-            fn(&[CombinatorArg<'a, T>], &mut Combinator<T>) -> Result<Output<T>, ParserError>,
-            &'a [CombinatorArg<'a, T>],
-        ),
-    ),
+    Func((FuncId, CombinatorFn<'a, T>, &'a [CombinatorArg<'a, T>])), // A combinator function. Not cached.
+    Rule((RuleId, CombinatorFn<'a, T>, &'a [CombinatorArg<'a, T>])), // A parser rule. Cached by id and arguments.
     Str(&'a str),
+    Flag(&'a str), // Configures the rule it is passed to. Not parsed.
+}
+
+impl<'a, T> CombinatorArg<'a, T> {
+    /// Gets the cache key of a rule applied to its arguments.
+    /// Rules without arguments are identified by their id alone.
+    pub fn get_rule_key(rule_id: RuleId, args: &[CombinatorArg<'a, T>]) -> RuleKey {
+        if args.is_empty() {
+            return Cow::Borrowed(rule_id);
+        }
+
+        Cow::Owned(format!(
+            "{}({})",
+            rule_id,
+            CombinatorArg::describe_args(args)
+        ))
+    }
+
+    /// Describes arguments so that different arguments get different descriptions.
+    fn describe_args(args: &[CombinatorArg<'a, T>]) -> String {
+        args.iter()
+            .map(|arg| match arg {
+                CombinatorArg::Func((func_id, _, args)) => {
+                    format!("{}({})", func_id, CombinatorArg::describe_args(args))
+                }
+                CombinatorArg::Rule((rule_id, _, args)) => {
+                    CombinatorArg::get_rule_key(rule_id, args).into_owned()
+                }
+                CombinatorArg::Str(string) => format!("{:?}", string),
+                CombinatorArg::Flag(flag) => flag.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /************************* OUTPUT *************************/
@@ -55,7 +111,18 @@ pub enum Output<T> {
 pub struct Combinator<T> {
     tokens: Vec<Token>,
     cursor: usize,
-    cache: HashMap<usize, HashMap<*const usize, CacheData<T>>>,
+    // The cached results of rules by cursor position and recovering mode.
+    cache: HashMap<(usize, bool), HashMap<RuleKey, CacheData<T>>>,
+    // The furthest cursor position a token failed to match at.
+    furthest_cursor: usize,
+    // The tokens that were expected at the furthest cursor position.
+    expected: Vec<String>,
+    // Maps byte offsets of the code the tokens come from back to lines and columns.
+    source_map: Option<SourceMap>,
+    // Whether parser rules should recover from errors instead of failing.
+    recovering: bool,
+    // The errors recovered from.
+    errors: Vec<ParserError>,
 }
 
 impl<T> Combinator<T>
@@ -68,12 +135,25 @@ where
             tokens,
             cursor: 0,
             cache: HashMap::new(),
+            furthest_cursor: 0,
+            expected: vec![],
+            source_map: None,
+            recovering: false,
+            errors: vec![],
         }
     }
 
+    /// Creates a new combinator object from the tokens passed in and the source map
+    /// of the code they come from.
+    pub fn with_source_map(tokens: Vec<Token>, source_map: SourceMap) -> Self {
+        let mut combinator = Self::new(tokens);
+        combinator.source_map = Some(source_map);
+        combinator
+    }
+
     /// Checks if the cursor is still in bounds, i.e. if cursor
     /// hasn't reached the end of the tokens.
-    fn is_inbounds(&self) -> bool {
+    pub fn is_inbounds(&self) -> bool {
         if self.cursor < self.tokens.len() {
             return true;
         }
@@ -100,34 +180,162 @@ where
         self.cursor = cursor;
     }
 
-    /// Gets the column number the combinator is at.
-    pub fn get_column(&mut self) -> usize {
+    /// Gets the span of the token the combinator is at.
+    pub fn get_span(&mut self) -> Span {
         // Check if there is a next token.
         if self.is_inbounds() {
-            self.tokens[self.cursor].cursor
+            self.tokens[self.cursor].span
         } else {
             let num_of_tokens = self.tokens.len();
             match num_of_tokens {
-                0 => 0, // Check if there is a token at all.
+                0 => Span::new(0, 0, 1, 1), // Check if there is a token at all.
                 _ => {
-                    // Get the ending column of the last token.
-                    let token = &self.tokens[num_of_tokens - 1];
-                    let token_length = token.token.clone().unwrap_or(String::new()).len();
-                    token.cursor + token_length + 1
+                    // Get the empty span right after the last token.
+                    let span = self.tokens[num_of_tokens - 1].span;
+                    match &self.source_map {
+                        Some(source_map) => source_map.get_span(span.end, span.end),
+                        // Without the code, assume the last token is on a single line
+                        // and has a character per byte.
+                        None => {
+                            let span_length = span.end - span.start;
+                            Span::new(span.end, span.end, span.line, span.column + span_length)
+                        }
+                    }
                 }
             }
         }
     }
 
+    /// Checks if parser rules should recover from errors instead of failing.
+    pub fn is_recovering(&self) -> bool {
+        self.recovering
+    }
+
+    /// Sets whether parser rules should recover from errors instead of failing.
+    /// Results are cached separately for each mode, as they are not valid in the other.
+    pub fn set_recovering(&mut self, recovering: bool) {
+        self.recovering = recovering;
+    }
+
+    /// Records an error that was recovered from, unless an error expecting the same tokens
+    /// is already recorded at its position.
+    pub fn add_error(&mut self, error: ParserError) {
+        if !self
+            .errors
+            .iter()
+            .any(|x| x.span == error.span && x.expected == error.expected)
+        {
+            self.errors.push(error);
+        }
+    }
+
+    /// Gets the number of errors recovered from.
+    pub fn get_error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Drops the errors recorded after the first `count` errors.
+    pub fn truncate_errors(&mut self, count: usize) {
+        self.errors.truncate(count);
+    }
+
+    /// Takes the errors recovered from.
+    pub fn take_errors(&mut self) -> Vec<ParserError> {
+        std::mem::take(&mut self.errors)
+    }
+
     /// Gets the combinator cache.
     pub fn get_cache_string(&mut self) -> String {
         format!("{:#?}", self.cache)
     }
 
+    /// Gets the next token if available but does not consume it.
+    pub fn peek_token(&self) -> Option<Token> {
+        self.tokens.get(self.cursor).cloned()
+    }
+
+    /// Records a token that was expected at the cursor position but not found.
+    /// Only the expectations at the furthest cursor position are kept.
+    pub fn expect(&mut self, expected: String) {
+        self.expect_at(self.cursor, expected);
+    }
+
+    /// Records a token that was expected at a cursor position but not found.
+    fn expect_at(&mut self, cursor: usize, expected: String) {
+        // Drop expectations of positions before the cursor.
+        if cursor > self.furthest_cursor {
+            self.furthest_cursor = cursor;
+            self.expected.clear();
+        }
+
+        // Add expectation if it is at the furthest position and not already recorded.
+        if cursor == self.furthest_cursor && !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
+    }
+
+    /// Clears the recorded expectations and starts tracking from the cursor position.
+    pub fn clear_expected(&mut self) {
+        self.furthest_cursor = self.cursor;
+        self.expected.clear();
+    }
+
+    /// Calls a parser function and, if it fails without consuming tokens, replaces
+    /// the expectations it recorded with a single description, e.g. "operator".
+    pub fn expect_as<F>(&mut self, description: &str, func: F) -> Result<Output<T>, ParserError>
+    where
+        F: FnOnce(&mut Combinator<T>) -> Result<Output<T>, ParserError>,
+    {
+        // Get the cursor and the recorded expectations.
+        let cursor = self.cursor;
+        let furthest_cursor = self.furthest_cursor;
+        let expected_count = self.expected.len();
+
+        let result = func(self);
+
+        // Check if the function failed at the cursor position.
+        if result.is_err() && self.furthest_cursor == cursor {
+            // Drop the expectations the function added.
+            if furthest_cursor == cursor {
+                self.expected.truncate(expected_count);
+            } else {
+                self.expected.clear();
+            }
+
+            self.expect(description.into());
+        }
+
+        result
+    }
+
+    /// Gets the furthest cursor position a token failed to match at.
+    pub fn get_furthest_cursor(&self) -> usize {
+        self.furthest_cursor
+    }
+
+    /// Creates an error at the furthest cursor position a token failed to match at,
+    /// with the tokens that were expected and the token that was found there.
+    pub fn get_furthest_error(&mut self, error: ErrorKind) -> ParserError {
+        // Temporarily move to the furthest cursor position.
+        let cursor = self.cursor;
+        self.cursor = self.furthest_cursor;
+        let span = self.get_span();
+        let found = self.peek_token().map(|token| match token.token {
+            Some(word) => format!("`{}`", word),
+            None => token.kind.to_string(),
+        });
+        self.cursor = cursor;
+
+        let mut error = ParserError::new(error, span);
+        error.expected = self.expected.clone();
+        error.found = found;
+        error
+    }
+
     /// Gets and consumes the next token if available.
     pub fn eat_token(&mut self) -> Result<Token, ParserError> {
         let cursor = self.cursor;
-        let column = self.get_column();
+        let span = self.get_span();
 
         if self.is_inbounds() {
             // Update parser position.
@@ -135,13 +343,13 @@ where
             return Ok(self.tokens[cursor].clone());
         }
 
-        Err(ParserError::new(ErrorKind::InputExhausted, column))
+        Err(ParserError::new(ErrorKind::InputExhausted, span))
     }
 
     /// Consumens and compares the next token with argument string.
     pub fn eat_compared_token(&mut self, token: &str) -> Result<(), ParserError> {
         let cursor = self.cursor;
-        let column = self.get_column();
+        let span = self.get_span();
 
         if self.is_inbounds() {
             if let Some(ref word) = self.tokens[cursor].token {
@@ -151,50 +359,248 @@ where
                     return Ok(());
                 }
 
-                return Err(ParserError::new(ErrorKind::TokensDontMatch, column));
+                self.expect(format!("`{}`", token));
+                return Err(ParserError::new(ErrorKind::TokensDontMatch, span));
             }
         }
 
-        Err(ParserError::new(ErrorKind::InputExhausted, column))
+        self.expect(format!("`{}`", token));
+        Err(ParserError::new(ErrorKind::InputExhausted, span))
     }
 
-    /// Stores result of a parse function call in cache if it does not already exist.
+    /// Stores result of a parse function call in cache.
     /// A parse function call corresponds to visiting a rule.
-    /// TODO: Fix the changing function address issue.
-    /// TODO: Test properly.
+    /// Replaces the left recursion seed of the rule if there is one.
     pub fn memoize(
         &mut self,
         cursor: usize,
-        rule_func_addr: *const usize,
+        rule_key: RuleKey,
         result: Result<Output<T>, ParserError>,
     ) {
-        // Check if the cursor already exists in map.
-        match self.cache.get_mut(&cursor) {
-            Some(rules) => {
-                // Cursor position exists in map.
-                // Check if rule doesn't already exists for cursor.
-                if rules.get(&rule_func_addr).is_none() {
-                    // Create cache data.
-                    let cache_data = CacheData::new(result, self.cursor - cursor);
-
-                    // Associate provided result with rule.
-                    rules.insert(rule_func_addr, cache_data);
-                }
+        // Create cache data.
+        let cache_data = CacheData::new(result, self.cursor - cursor);
+
+        // Get rules for cursor or create new rules if cursor position does not exist in map.
+        let rules = self.cache.entry((cursor, self.recovering)).or_default();
+
+        // Associate provided result with rule.
+        rules.insert(rule_key, cache_data);
+    }
+
+    /// Gets the cached result of a rule at a cursor position if there is one.
+    pub fn get_cached(
+        &self,
+        cursor: usize,
+        rule_key: &str,
+    ) -> Option<(Result<Output<T>, ParserError>, usize)> {
+        self.cache
+            .get(&(cursor, self.recovering))
+            .and_then(|rules| rules.get(rule_key))
+            .map(|CacheData { data, skip, .. }| (data.clone(), *skip))
+    }
+
+    /// Parses a rule at the cursor position.
+    /// Reuses the cached result if the rule has already been visited at the cursor position,
+    /// otherwise calls the rule function and caches its result.
+    ///
+    /// Left-recursive rules are supported by seed growing as described in
+    /// "Packrat Parsers Can Support Left Recursion" by Warth et al.
+    pub fn apply_rule<'a>(
+        &mut self,
+        rule_id: RuleId,
+        func: CombinatorFn<'a, T>,
+        args: &[CombinatorArg<'a, T>],
+    ) -> Result<Output<T>, ParserError> {
+        let cursor = self.cursor;
+        let span = self.get_span();
+        let rule_key = CombinatorArg::get_rule_key(rule_id, args);
+
+        // Check if rule is already cached for cursor.
+        if let Some(cache_data) = self
+            .cache
+            .get_mut(&(cursor, self.recovering))
+            .and_then(|rules| rules.get_mut(&rule_key))
+        {
+            // Rule is being called by itself at the same cursor position.
+            if cache_data.is_seed {
+                cache_data.is_left_recursive = true;
+            }
+
+            let data = cache_data.data.clone();
+            let skip = cache_data.skip;
+            let (expected_cursor, expected) = cache_data.expected.clone();
+
+            // Record the expectations as if the rule was parsed again.
+            for expected in expected {
+                self.expect_at(expected_cursor, expected);
+            }
+
+            // Needed to advance the combinator state.
+            if data.is_ok() {
+                self.update_state(Some(skip));
+            }
+
+            return data;
+        }
+
+        // Plant a failing seed so that left-recursive calls fail instead of looping forever.
+        self.plant_seed(
+            cursor,
+            &rule_key,
+            Err(ParserError::new(ErrorKind::LeftRecursion, span)),
+        );
+
+        // Get the recorded expectations.
+        let furthest_cursor = self.furthest_cursor;
+        let expected_count = self.expected.len();
+
+        // Call the function with combinator as argument.
+        let mut result = func(args, self);
+
+        // Grow the seed if the rule turned out to be left-recursive.
+        if self.is_left_recursive(cursor, &rule_key) {
+            result = self.grow_seed(cursor, &rule_key, func, args, result);
+        }
+
+        // Cache parser result.
+        self.memoize(cursor, rule_key.clone(), result.clone());
+
+        // Cache the expectations the rule added.
+        let expected = if self.furthest_cursor == furthest_cursor {
+            self.expected[expected_count.min(self.expected.len())..].to_vec()
+        } else {
+            self.expected.clone()
+        };
+        if let Some(cache_data) = self
+            .cache
+            .get_mut(&(cursor, self.recovering))
+            .and_then(|rules| rules.get_mut(&rule_key))
+        {
+            cache_data.expected = (self.furthest_cursor, expected);
+        }
+
+        result
+    }
+
+    /// Stores a left recursion seed for a rule at a cursor position.
+    fn plant_seed(
+        &mut self,
+        cursor: usize,
+        rule_key: &RuleKey,
+        result: Result<Output<T>, ParserError>,
+    ) {
+        // Keep track of left recursion detected with previous seeds.
+        let is_left_recursive = self.is_left_recursive(cursor, rule_key);
+
+        // Create seed.
+        let mut cache_data = CacheData::new(result, self.cursor - cursor);
+        cache_data.is_seed = true;
+        cache_data.is_left_recursive = is_left_recursive;
+
+        self.cache
+            .entry((cursor, self.recovering))
+            .or_default()
+            .insert(rule_key.clone(), cache_data);
+    }
+
+    /// Checks if a rule called itself at a cursor position while being parsed.
+    fn is_left_recursive(&self, cursor: usize, rule_key: &str) -> bool {
+        self.cache
+            .get(&(cursor, self.recovering))
+            .and_then(|rules| rules.get(rule_key))
+            .is_some_and(|cache_data| cache_data.is_left_recursive)
+    }
+
+    /// Repeatedly reparses a left-recursive rule, using its previous result as seed,
+    /// until the rule no longer consumes more tokens than before.
+    ///
+    /// The rule is the head of the left recursion and the other rules parsed at its cursor
+    /// position are involved in it, e.g. `difference` in `term = difference` and
+    /// `difference = term '-' '1' | '1'`. Their results depend on the seed, so they are
+    /// reparsed with every seed and the ones computed along with the longest parse are kept.
+    fn grow_seed<'a>(
+        &mut self,
+        cursor: usize,
+        rule_key: &RuleKey,
+        func: CombinatorFn<'a, T>,
+        args: &[CombinatorArg<'a, T>],
+        seed: Result<Output<T>, ParserError>,
+    ) -> Result<Output<T>, ParserError> {
+        let mut result = seed;
+        let mut end = self.cursor;
+
+        // Get the results of the involved rules computed along with the seed.
+        let mut involved = self.take_involved(cursor);
+
+        // Nothing to grow if the rule failed without its recursive alternatives.
+        while result.is_ok() {
+            // Use the last result as seed.
+            self.plant_seed(cursor, rule_key, result.clone());
+
+            // Reparse the rule from the start.
+            self.set_cursor(cursor);
+            let grown_result = func(args, self);
+
+            // Forget results of the involved rules that were computed with the last seed.
+            let grown_involved = self.take_involved(cursor);
+
+            // Stop growing when parsing fails or doesn't get further.
+            if grown_result.is_err() || self.cursor <= end {
+                break;
             }
-            None => {
-                // Cursor position does not exist in map.
-                // Create new rules for cursor.
-                let mut rules = HashMap::new();
 
-                // Create cache data
-                let cache_data = CacheData::new(result, self.cursor - cursor);
+            result = grown_result;
+            end = self.cursor;
+            involved = grown_involved;
+        }
+
+        // Keep the results of the involved rules computed along with the longest parse.
+        if let Some(rules) = self.cache.get_mut(&(cursor, self.recovering)) {
+            rules.extend(involved);
+        }
 
-                // Associate provided result with rule.
-                rules.insert(rule_func_addr, cache_data);
+        // Move to the end of the longest parse.
+        self.set_cursor(if result.is_ok() { end } else { cursor });
 
-                // Add rules for cursor.
-                self.cache.insert(cursor, rules);
+        result
+    }
+
+    /// Takes the cached results at a cursor position, except for the seeds of the rules
+    /// still being parsed there.
+    fn take_involved(&mut self, cursor: usize) -> HashMap<RuleKey, CacheData<T>> {
+        match self.cache.get_mut(&(cursor, self.recovering)) {
+            Some(rules) => {
+                let (seeds, involved) = rules
+                    .drain()
+                    .partition(|(_, cache_data)| cache_data.is_seed);
+                *rules = seeds;
+                involved
+            }
+            None => HashMap::new(),
+        }
+    }
+
+    /// Parses a single argument.
+    fn parse_arg<'a>(
+        arg: &CombinatorArg<'a, T>,
+        combinator: &mut Combinator<T>,
+    ) -> Result<Output<T>, ParserError> {
+        // Check type of argument.
+        match arg {
+            // It is a combinator function argument.
+            CombinatorArg::Func((_, func, arguments)) => func(arguments, combinator),
+            // It is a parser rule argument.
+            CombinatorArg::Rule((rule_id, func, arguments)) => {
+                combinator.apply_rule(rule_id, *func, arguments)
             }
+            // It is a string argument
+            // Compare and consume token.
+            CombinatorArg::Str(token) => combinator
+                .eat_compared_token(token)
+                .map(|_| Output::Str(token.to_string())),
+            // It is a flag argument.
+            // Consumes nothing.
+            CombinatorArg::Flag(_) => Ok(Output::Empty),
         }
     }
 
@@ -206,78 +612,26 @@ where
     where
         T: Debug + Clone,
     {
-        // Get cursor and column.
+        // Get cursor and span.
         let cursor = combinator.cursor;
-        let column = combinator.get_column();
+        let span = combinator.get_span();
         let mut asts: Vec<Output<T>> = Vec::new();
         let mut problem: Option<ParserError> = None;
 
         // Loop through arguments.
         for arg in args {
-            // Check type of argument.
-            match arg {
-                CombinatorArg::Func((func, arguments)) => {
-                    // It is a function argument
-                    // Get function address
-                    let func_addr = get_func_addr(func);
-
-                    // Check if there are rules for current cursor position
-                    // and that resulting rules map contain the function address.
-                    let rules = combinator.cache.get(&cursor);
-                    if rules.is_some() && rules.unwrap().get(&func_addr).is_some() {
-                        // Get previously stored result.
-                        let CacheData { data, skip } =
-                            (*rules.unwrap().get(&func_addr).unwrap()).clone();
-
-                        // Check if data in cached data is an error.
-                        if data.is_err() {
-                            // Retrieve problem.
-                            problem = Some(data.unwrap_err());
-
-                            // Break out of loop.
-                            break;
-                        } else {
-                            // Add data to list.
-                            asts.push(data.unwrap());
-
-                            // Needed to advance the combinator state.
-                            combinator.update_state(Some(skip));
-                        }
-                    } else {
-                        // If rule is not already cached
-                        // Call the function with combinator as argument.
-                        let ast = func(arguments, combinator);
-
-                        // Check if result of parse function is an error.
-                        if ast.is_err() {
-                            // Retrieve problem.
-                            problem = Some(ast.unwrap_err());
-
-                            // Break out of loop.
-                            break;
-                        } else {
-                            // Add data to list.
-                            asts.push(ast.unwrap());
-                        }
-                    }
-                }
-                CombinatorArg::Str(token) => {
-                    // It is a string argument
-                    // Compare and consume token.
-                    let result = combinator.eat_compared_token(token);
-
-                    // Check if result of parse function is an error.
-                    if result.is_err() {
-                        // Retrieve problem.
-                        problem = Some(result.unwrap_err());
-
-                        // Break out of loop.
-                        break;
-                    } else {
-                        // Add data to list.
-                        asts.push(Output::Str(token.to_string()));
-                    }
-                }
+            let ast = Combinator::parse_arg(arg, combinator);
+
+            // Check if result of parse function is an error.
+            if ast.is_err() {
+                // Retrieve problem.
+                problem = Some(ast.unwrap_err());
+
+                // Break out of loop.
+                break;
+            } else {
+                // Add data to list.
+                asts.push(ast.unwrap());
             }
         }
 
@@ -299,88 +653,30 @@ where
     where
         T: Debug + Clone,
     {
-        // Get cursor and column.
+        // Get cursor and span.
         let cursor = combinator.cursor;
-        let column = combinator.get_column();
+        let span = combinator.get_span();
         let mut asts: Vec<Output<T>> = Vec::new();
         let mut parsed_successfully = false;
         let mut alternative_index: u8 = 0;
 
         // Loop through arguments.
         for (index, arg) in args.iter().enumerate() {
-            // Check type of argument.
-            match arg {
-                CombinatorArg::Func((func, arguments)) => {
-                    // It is a function argument
-                    // Get function address
-                    let func_addr = get_func_addr(func);
-
-                    // Check if there are rules for current cursor position
-                    // and that resulting rules map contain the function address.
-                    let rules = combinator.cache.get(&cursor);
-                    if rules.is_some() && rules.unwrap().get(&func_addr).is_some() {
-                        // Get previously stored result.
-                        let CacheData { data, skip } =
-                            (*rules.unwrap().get(&func_addr).unwrap()).clone();
-
-                        // Check if data in cached data is ok.
-                        if data.is_ok() {
-                            // Parsing successful.
-                            parsed_successfully = true;
-
-                            // Set alternative index.
-                            alternative_index = index as _;
-
-                            // Add data to list.
-                            asts.push(data.unwrap());
-
-                            // Needed to advance the combinator state.
-                            combinator.update_state(Some(skip));
-
-                            // Break out of loop.
-                            break;
-                        }
-                    } else {
-                        // If rule is not already cached
-                        // Call the function with combinator as argument.
-                        let ast = func(arguments, combinator);
-
-                        // Check if result of parse function is ok.
-                        if ast.is_ok() {
-                            // Parsing successful.
-                            parsed_successfully = true;
-
-                            // Set alternative index.
-                            alternative_index = index as _;
-
-                            // Add data to list.
-                            asts.push(ast.unwrap());
-
-                            // Break out of loop.
-                            break;
-                        }
-                    }
-                }
-                CombinatorArg::Str(token) => {
-                    // It is a string argument
-                    // Compare and consume token.
-                    let result = combinator.eat_compared_token(token);
+            let ast = Combinator::parse_arg(arg, combinator);
 
-                    // Check if result of parse function is an error.
-                    if result.is_ok() {
-                        // Parsing successful.
-                        parsed_successfully = true;
+            // Check if result of parse function is ok.
+            if ast.is_ok() {
+                // Parsing successful.
+                parsed_successfully = true;
 
-                        // Set alternative index.
-                        alternative_index = index as _;
+                // Set alternative index.
+                alternative_index = index as _;
 
-                        // Add data to list.
-                        asts.push(Output::Str(token.to_string()));
+                // Add data to list.
+                asts.push(ast.unwrap());
 
-                        // Break out of loop.
-                        break;
-                    }
-                }
+                // Break out of loop.
+                break;
             }
         }
 
@@ -388,7 +684,13 @@ where
         if !parsed_successfully {
             // Revert state.
             combinator.set_cursor(cursor);
-            return Err(ParserError::new(ErrorKind::AlternativesDontMatch, column));
+
+            // Report what the alternatives expected if they got past the cursor.
+            if combinator.get_furthest_cursor() >= cursor {
+                return Err(combinator.get_furthest_error(ErrorKind::AlternativesDontMatch));
+            }
+
+            return Err(ParserError::new(ErrorKind::AlternativesDontMatch, span));
         }
 
         Ok(Output::Alt {
@@ -405,9 +707,9 @@ where
     where
         T: Debug + Clone,
     {
-        // Get cursor and column.
+        // Get cursor and span.
         let cursor = combinator.cursor;
-        let column = combinator.get_column();
+        let span = combinator.get_span();
         let mut asts: Vec<Output<T>> = Vec::new();
         let mut parsed_successfully = true;
 
@@ -431,7 +733,7 @@ where
         if !parsed_successfully {
             // Revert state.
             combinator.set_cursor(cursor);
-            return Err(ParserError::new(ErrorKind::CantMatchAtLeastARule, column));
+            return Err(ParserError::new(ErrorKind::CantMatchAtLeastARule, span));
         }
 
         Ok(Output::Values(asts))
@@ -517,12 +819,12 @@ where
     where
         T: Debug + Clone,
     {
-        // Get cursor and column.
+        // Get cursor and span.
         let cursor = combinator.cursor;
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         let result = match Combinator::parse(args, combinator) {
-            Ok(_) => Err(ParserError::new(ErrorKind::ExpectedRuleToFail, column)),
+            Ok(_) => Err(ParserError::new(ErrorKind::ExpectedRuleToFail, span)),
             Err(_) => Ok(Output::Empty),
         };
 
@@ -533,5 +835,286 @@ where
     }
 }
 
-/// TODO
-mod tests {}
+/************************* TESTS *************************/
+
+#[cfg(test)]
+mod tests {
+    use super::{Combinator, CombinatorArg, Output};
+    use crate::{errors::ParserError, kinds::ErrorKind};
+    use astro_lexer::{Lexer, Span};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Number of times `counted_rule` has been called.
+    static COUNTED_RULE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    // sum = sum '+' '1' | '1'
+    fn sum<'a>(
+        _args: &[CombinatorArg<'a, ()>],
+        combinator: &mut Combinator<()>,
+    ) -> Result<Output<()>, ParserError> {
+        alt!(
+            combinator,
+            parse!(
+                CombinatorArg::Rule(("sum", sum as _, &vec![])),
+                s!("+"),
+                s!("1")
+            ),
+            s!("1")
+        )
+    }
+
+    // difference = term '-' '1' | '1'
+    fn difference<'a>(
+        _args: &[CombinatorArg<'a, ()>],
+        combinator: &mut Combinator<()>,
+    ) -> Result<Output<()>, ParserError> {
+        alt!(
+            combinator,
+            parse!(
+                CombinatorArg::Rule(("term", term as _, &vec![])),
+                s!("-"),
+                s!("1")
+            ),
+            s!("1")
+        )
+    }
+
+    // term = difference
+    fn term<'a>(
+        _args: &[CombinatorArg<'a, ()>],
+        combinator: &mut Combinator<()>,
+    ) -> Result<Output<()>, ParserError> {
+        Combinator::apply_rule(combinator, "difference", difference as _, &vec![])
+    }
+
+    fn get_combinator_for_code(code: String) -> Combinator<()> {
+        let tokens = Lexer::new(code).lex().unwrap();
+        Combinator::new(tokens)
+    }
+
+    fn counted_rule<'a>(
+        args: &[CombinatorArg<'a, ()>],
+        combinator: &mut Combinator<()>,
+    ) -> Result<Output<()>, ParserError> {
+        COUNTED_RULE_CALLS.fetch_add(1, Ordering::SeqCst);
+        Combinator::parse(args, combinator)
+    }
+
+    #[test]
+    fn span_at_end_of_input() {
+        // Last token spans two lines and has a multibyte character.
+        let mut lexer = Lexer::new("a \"é\nbc\"".into());
+        let tokens = lexer.lex().unwrap();
+        let combinator =
+            &mut Combinator::<()>::with_source_map(tokens, lexer.get_source_map().clone());
+        combinator.set_cursor(2);
+
+        assert_eq!(combinator.get_span(), Span::new(9, 9, 2, 4));
+    }
+
+    #[test]
+    fn rules_at_same_cursor_dont_collide() {
+        // Two rules sharing the same function.
+        let combinator = &mut get_combinator_for_code("b".into());
+        let combinator_result = alt!(
+            combinator,
+            CombinatorArg::Rule(("rule_a", Combinator::parse as _, &vec![s!("a")])),
+            CombinatorArg::Rule(("rule_b", Combinator::parse as _, &vec![s!("b")]))
+        );
+
+        assert_eq!(
+            combinator_result,
+            Ok(Output::Alt {
+                value: Box::new(Output::Values(vec![Output::Values(vec![Output::Str(
+                    "b".into()
+                )])])),
+                index: 1,
+            })
+        );
+        assert!(combinator
+            .get_cached(0, "rule_a(\"a\")")
+            .unwrap()
+            .0
+            .is_err());
+        assert_eq!(
+            combinator.get_cached(0, "rule_b(\"b\")"),
+            Some((Ok(Output::Values(vec![Output::Str("b".into())])), 1))
+        );
+    }
+
+    #[test]
+    fn rule_arguments_dont_collide() {
+        // One rule applied to different arguments.
+        let combinator = &mut get_combinator_for_code("b".into());
+        let combinator_result = alt!(
+            combinator,
+            CombinatorArg::Rule(("rule", Combinator::parse as _, &vec![s!("a")])),
+            CombinatorArg::Rule(("rule", Combinator::parse as _, &vec![s!("b")]))
+        );
+
+        assert_eq!(
+            combinator_result,
+            Ok(Output::Alt {
+                value: Box::new(Output::Values(vec![Output::Values(vec![Output::Str(
+                    "b".into()
+                )])])),
+                index: 1,
+            })
+        );
+        assert!(combinator.get_cached(0, "rule(\"a\")").unwrap().0.is_err());
+        assert!(combinator.get_cached(0, "rule(\"b\")").unwrap().0.is_ok());
+    }
+
+    #[test]
+    fn combinator_arguments_dont_collide() {
+        // One rule applied to different combinators with the same arguments.
+        let combinator = &mut get_combinator_for_code("b".into());
+        let combinator_result = alt!(
+            combinator,
+            CombinatorArg::Rule(("rule", Combinator::parse as _, &vec![not!(s!("b"))])),
+            CombinatorArg::Rule(("rule", Combinator::parse as _, &vec![opt!(s!("b"))]))
+        );
+
+        assert_eq!(
+            combinator_result,
+            Ok(Output::Alt {
+                value: Box::new(Output::Values(vec![Output::Values(vec![Output::Values(
+                    vec![Output::Str("b".into())]
+                )])])),
+                index: 1,
+            })
+        );
+        assert!(combinator
+            .get_cached(0, "rule(not(\"b\"))")
+            .unwrap()
+            .0
+            .is_err());
+        assert!(combinator
+            .get_cached(0, "rule(opt(\"b\"))")
+            .unwrap()
+            .0
+            .is_ok());
+    }
+
+    #[test]
+    fn recovering_results_are_cached_separately() {
+        let combinator = &mut get_combinator_for_code("a".into());
+        let combinator_result = parse!(
+            combinator,
+            CombinatorArg::Rule(("rule", Combinator::parse as _, &vec![s!("a")]))
+        );
+
+        assert!(combinator_result.is_ok());
+
+        // Results cached in one mode are not used in the other.
+        combinator.set_recovering(true);
+        assert!(combinator.get_cached(0, "rule(\"a\")").is_none());
+
+        // Results are kept when the mode changes.
+        combinator.set_recovering(false);
+        assert!(combinator.get_cached(0, "rule(\"a\")").is_some());
+    }
+
+    #[test]
+    fn cached_rule_is_reused() {
+        // Second alternative reuses the rule parsed by the first one.
+        let combinator = &mut get_combinator_for_code("a".into());
+        let combinator_result = alt!(
+            combinator,
+            parse!(
+                CombinatorArg::Rule(("counted_rule", counted_rule as _, &vec![s!("a")])),
+                s!("x")
+            ),
+            CombinatorArg::Rule(("counted_rule", counted_rule as _, &vec![s!("a")]))
+        );
+
+        assert_eq!(
+            combinator_result,
+            Ok(Output::Alt {
+                value: Box::new(Output::Values(vec![Output::Values(vec![Output::Str(
+                    "a".into()
+                )])])),
+                index: 1,
+            })
+        );
+        assert_eq!(combinator.get_cursor(), 1);
+        assert_eq!(COUNTED_RULE_CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn cached_rule_records_expectations() {
+        // Failed rule is parsed and then reused from the cache.
+        let combinator = &mut get_combinator_for_code("b".into());
+        let combinator_result_1 =
+            combinator.apply_rule("rule_a", Combinator::parse as _, &vec![s!("a")]);
+        combinator.clear_expected();
+        let combinator_result_2 =
+            combinator.apply_rule("rule_a", Combinator::parse as _, &vec![s!("a")]);
+
+        assert!(combinator_result_1.is_err());
+        assert_eq!(combinator_result_1, combinator_result_2);
+        assert_eq!(
+            combinator
+                .get_furthest_error(ErrorKind::AlternativesDontMatch)
+                .expected,
+            vec!["`a`".to_string()]
+        );
+    }
+
+    #[test]
+    fn left_recursion() {
+        let combinator = &mut get_combinator_for_code("1 + 1 + 1 x".into());
+        let combinator_result = combinator.apply_rule("sum", sum as _, &vec![]);
+
+        // Parses as ((1 + 1) + 1).
+        assert_eq!(
+            combinator_result,
+            Ok(Output::Alt {
+                value: Box::new(Output::Values(vec![Output::Values(vec![
+                    Output::Alt {
+                        value: Box::new(Output::Values(vec![Output::Values(vec![
+                            Output::Alt {
+                                value: Box::new(Output::Values(vec![Output::Str("1".into())])),
+                                index: 1,
+                            },
+                            Output::Str("+".into()),
+                            Output::Str("1".into()),
+                        ])])),
+                        index: 0,
+                    },
+                    Output::Str("+".into()),
+                    Output::Str("1".into()),
+                ])])),
+                index: 0,
+            })
+        );
+        assert_eq!(combinator.get_cursor(), 5);
+    }
+
+    #[test]
+    fn indirect_left_recursion() {
+        let combinator = &mut get_combinator_for_code("1 - 1 - 1".into());
+        let combinator_result = combinator.apply_rule("term", term as _, &vec![]);
+
+        assert!(combinator_result.is_ok());
+        assert_eq!(combinator.get_cursor(), 5);
+        assert_eq!(combinator.get_cached(0, "term").unwrap().1, 5);
+
+        // Rule involved in the left recursion is reused at the same cursor position.
+        combinator.set_cursor(0);
+        let combinator_result = combinator.apply_rule("difference", difference as _, &vec![]);
+
+        assert!(combinator_result.is_ok());
+        assert_eq!(combinator.get_cursor(), 5);
+    }
+
+    #[test]
+    fn left_recursion_without_seed() {
+        // Fails when the non-recursive alternative doesn't match.
+        let combinator = &mut get_combinator_for_code("+ 1".into());
+        let combinator_result = combinator.apply_rule("sum", sum as _, &vec![]);
+
+        assert!(combinator_result.is_err());
+        assert_eq!(combinator.get_cursor(), 0);
+    }
+}
@@ -1,14 +1,49 @@
 use crate::kinds::ErrorKind;
+use astro_lexer::Span;
+use std::fmt;
 
-/// Error from lexing.
+/// Error from parsing.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParserError {
     pub error: ErrorKind,
-    pub column: usize,
+    pub span: Span,
+    // The tokens that were expected where the error occured.
+    pub expected: Vec<String>,
+    // The token that was found where the error occured, if any.
+    pub found: Option<String>,
 }
 
 impl ParserError {
-    pub fn new(error: ErrorKind, column: usize) -> Self {
-        Self { error, column }
+    pub fn new(error: ErrorKind, span: Span) -> Self {
+        Self {
+            error,
+            span,
+            expected: vec![],
+            found: None,
+        }
+    }
+}
+
+impl fmt::Display for ParserError {
+    /// Formats error as "line:column: expected `a`, `b` or `c`, found `d`".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.span.line, self.span.column)?;
+
+        // Fall back to the error kind if nothing was expected.
+        if self.expected.is_empty() {
+            write!(f, "{:?}", self.error)?;
+        } else {
+            let count = self.expected.len();
+            write!(f, "expected {}", self.expected[0])?;
+            for (index, expected) in self.expected.iter().enumerate().skip(1) {
+                let separator = if index == count - 1 { " or" } else { "," };
+                write!(f, "{} {}", separator, expected)?;
+            }
+        }
+
+        match self.found {
+            Some(ref found) => write!(f, ", found {}", found),
+            None => write!(f, ", found end of input"),
+        }
     }
 }
@@ -8,12 +8,129 @@ pub enum ErrorKind {
     CantMatchAtLeastARule,
     OneOfRulesFailed,
     ExpectedRuleToFail,
+    LeftRecursion,
+    ExpectedSpace,
+    ExpectedNoSpace,
     ExpectedIntegerLiteral,
     ExpectedFloatLiteral,
+    ExpectedSymbolLiteral,
     ExpectedComma,
+    ExpectedDot,
     ExpectedNewlines,
     ExpectedListArguments,
     ExpectedListLiteral,
     ExpectedTupleArguments,
     ExpectedTupleLiteral,
+    ExpectedDictArgument,
+    ExpectedDictArguments,
+    ExpectedDictLiteral,
+    ExpectedObjectArgument,
+    ExpectedObjectArguments,
+    ExpectedObjectLiteral,
+    ExpectedContainer,
+    ExpectedLiteral,
+    ExpectedComprehensionExpression,
+    ExpectedIterExpression,
+    ExpectedIterArgument,
+    ExpectedIterArguments,
+    ExpectedGeneratorComprehension,
+    ExpectedListComprehension,
+    ExpectedDictComprehension,
+    ExpectedComprehension,
+    ExpectedCallArgument,
+    ExpectedCallArguments,
+    ExpectedCallPostfix,
+    ExpectedDotNotationPostfix,
+    ExpectedIndexArguments,
+    ExpectedIndexPostfix,
+    ExpectedTupleIndexPostfix,
+    ExpectedPostfix,
+    ExpectedId,
+    ExpectedPatternId,
+    ExpectedSubAtom,
+    ExpectedKeywordOperator,
+    ExpectedInfixOperator,
+    ExpectedPrefixOperator,
+    ExpectedPostfixOperator,
+    ExpectedAtom,
+    ExpectedCoefficientExpression,
+    ExpectedPrePostfixAtom,
+    ExpectedInfixExpression,
+    ExpectedTernaryOperator,
+    ExpectedCommandNotation,
+    ExpectedReturnExpression,
+    ExpectedYieldExpression,
+    ExpectedContinueExpression,
+    ExpectedBreakExpression,
+    ExpectedFallthroughExpression,
+    ExpectedControlFlowExpression,
+    ExpectedFixExpression,
+    ExpectedSpread,
+    ExpectedRange,
+    ExpectedSimpleExpression,
+    ExpectedExpression,
+    ExpectedBlockExpression,
+    ExpectedExpressions,
+    ExpectedProgram,
+    ExpectedTypeSubAtom,
+    ExpectedTypePostfix,
+    ExpectedTypeTuple,
+    ExpectedTypeAtom,
+    ExpectedTypeBinop,
+    ExpectedTypeFunctionArguments,
+    ExpectedTypeRelationOperator,
+    ExpectedTypeRelationArgument,
+    ExpectedTypeRelationArguments,
+    ExpectedTypeExpression,
+    ExpectedLhsRefPostfix,
+    ExpectedLhsRef,
+    ExpectedLhsPatternArgument,
+    ExpectedLhsPatternArguments,
+    ExpectedLhsTuplePattern,
+    ExpectedLhsDictPattern,
+    ExpectedLhsListPattern,
+    ExpectedLhsObjectPattern,
+    ExpectedLhsContainerPattern,
+    ExpectedLhsPattern,
+    ExpectedMatchPatternArgument,
+    ExpectedMatchPatternArguments,
+    ExpectedMatchTuplePattern,
+    ExpectedMatchDictPattern,
+    ExpectedMatchListPattern,
+    ExpectedMatchContainerPattern,
+    ExpectedMatchSubPattern,
+    ExpectedMatchPattern,
+    ExpectedMatchArgument,
+    ExpectedMatchArguments,
+    ExpectedMatchExpression,
+    ExpectedElifExpression,
+    ExpectedElseExpression,
+    ExpectedCondition,
+    ExpectedIfExpression,
+    ExpectedLoopExpression,
+    ExpectedForExpression,
+    ExpectedWhileExpression,
+    ExpectedConditionalExpression,
+    ExpectedTypeGenericArgument,
+    ExpectedTypeGenericArguments,
+    ExpectedTypeParentArguments,
+    ExpectedTypeCompleteArguments,
+    ExpectedField,
+    ExpectedTypeField,
+    ExpectedTypeFields,
+    ExpectedTypeDeclaration,
+    ExpectedEnumVariantField,
+    ExpectedEnumVariantFields,
+    ExpectedEnumVariantTupleArgument,
+    ExpectedEnumVariantTupleArguments,
+    ExpectedEnumVariant,
+    ExpectedEnumVariants,
+    ExpectedEnumDeclaration,
+    ExpectedFunctionArgument,
+    ExpectedFunctionArguments,
+    ExpectedFunction,
+    ExpectedClosure,
+    ExpectedSubject,
+    ExpectedAssignmentOperator,
+    ExpectedAssignment,
 }
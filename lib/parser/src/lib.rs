@@ -3,8 +3,8 @@ pub mod macros;
 pub mod combinator;
 pub mod errors;
 pub mod kinds;
+pub mod operators;
 pub mod parser;
-pub mod utils;
 
 #[cfg(test)]
 mod parser_tests;
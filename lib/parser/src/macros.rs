@@ -5,19 +5,22 @@ macro_rules! s {
     };
 }
 
+/// Creates a parser rule argument identified by the rule's name and arguments.
 #[macro_export]
 macro_rules! f {
     ($func:ident, $arg0:expr $(, $args:expr)* ) => {
-        CombinatorArg::Func(
+        CombinatorArg::Rule(
             (
+                stringify!($func),
                 Parser::$func as _,
                 &vec![$arg0 $(, $args)*],
             )
         )
     };
     ($func:ident) => {
-        CombinatorArg::Func(
+        CombinatorArg::Rule(
             (
+                stringify!($func),
                 Parser::$func as _,
                 &vec![],
             )
@@ -36,6 +39,7 @@ macro_rules! parse {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(parse),
                 Combinator::parse as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -54,6 +58,7 @@ macro_rules! alt {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(alt),
                 Combinator::alt as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -72,6 +77,7 @@ macro_rules! more {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(more),
                 Combinator::more as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -90,6 +96,7 @@ macro_rules! optmore {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(optmore),
                 Combinator::opt_more as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -108,6 +115,7 @@ macro_rules! opt {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(opt),
                 Combinator::opt as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -126,6 +134,7 @@ macro_rules! and {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(and),
                 Combinator::and as _,
                 &vec![$arg0 $(, $args)*],
             )
@@ -144,6 +153,7 @@ macro_rules! not {
     ($arg0:expr $(, $args:expr)* ) => {
         CombinatorArg::Func(
             (
+                stringify!(not),
                 Combinator::not as _,
                 &vec![$arg0 $(, $args)*],
             )
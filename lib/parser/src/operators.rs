@@ -0,0 +1,147 @@
+use astro_codegen::asts::{Fixity, SimpleExpr};
+use std::{iter::Peekable, vec::IntoIter};
+
+/************************* ASSOCIATIVITY *************************/
+
+/// How operators of the same precedence are grouped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Associativity {
+    Left,  // `a - b - c` is `(a - b) - c`.
+    Right, // `a ^ b ^ c` is `a ^ (b ^ c)`.
+}
+
+/************************* PRECEDENCE *************************/
+
+/// Precedence of the prefix `not` operator.
+/// Binds looser than comparison so that `not a == b` is `not (a == b)`.
+pub const NOT_PRECEDENCE: u8 = 3;
+
+/// Precedence of operators not in the table, e.g. user-defined operators.
+pub const DEFAULT_PRECEDENCE: u8 = 5;
+
+/// Gets the precedence and associativity of an infix operator.
+/// Operators with higher precedence bind tighter.
+///
+/// | Precedence | Operators                                                       | Associativity |
+/// |------------|-----------------------------------------------------------------|---------------|
+/// | 12         | `^`                                                             | Right         |
+/// | 11         | `*` `/` `÷` `×` `%` `mod`                                       | Left          |
+/// | 10         | `+` `-`                                                         | Left          |
+/// | 9          | `<<` `>>` `>>>`                                                 | Left          |
+/// | 8          | `&`                                                             | Left          |
+/// | 7          | `~`                                                             | Left          |
+/// | 6          | `\|`                                                            | Left          |
+/// | 5          | Others                                                          | Left          |
+/// | 4          | `==` `!=` `≠` `≈` `<` `>` `<=` `>=` `is` `is not` `in` `not in` | Left          |
+/// | 3          | `not` (prefix)                                                  | Right         |
+/// | 2          | `and`                                                           | Left          |
+/// | 1          | `or`                                                            | Left          |
+pub fn get_infix_precedence(operator: &str) -> (u8, Associativity) {
+    get_standard_precedence(operator).unwrap_or((DEFAULT_PRECEDENCE, Associativity::Left))
+}
+
+/// Gets the precedence and associativity of an operator if it is in the precedence table.
+fn get_standard_precedence(operator: &str) -> Option<(u8, Associativity)> {
+    let precedence = match operator {
+        "^" => (12, Associativity::Right),
+        "*" | "/" | "÷" | "×" | "%" | "mod" => (11, Associativity::Left),
+        "+" | "-" => (10, Associativity::Left),
+        "<<" | ">>" | ">>>" => (9, Associativity::Left),
+        "&" => (8, Associativity::Left),
+        "~" => (7, Associativity::Left),
+        "|" => (6, Associativity::Left),
+        "==" | "!=" | "≠" | "≈" | "<" | ">" | "<=" | ">=" | "is" | "is not" | "in" | "not in" => {
+            (4, Associativity::Left)
+        }
+        "and" => (2, Associativity::Left),
+        "or" => (1, Associativity::Left),
+        _ => return None,
+    };
+
+    Some(precedence)
+}
+
+/// Checks if an operator token is reserved for other syntax, e.g. `=` in assignments
+/// and `:` in dict literals, and so can't be used as a prefix, postfix or infix operator.
+pub fn is_reserved_operator(operator: &str) -> bool {
+    match operator {
+        "=" | ":" | "?" | "=>" | "->" | "::" | "<:" | ">:" => true,
+        // Comparison operators.
+        "==" | "!=" | "<=" | ">=" => false,
+        // Compound assignment operators.
+        _ => operator.ends_with('='),
+    }
+}
+
+/// Checks if an operator can be used as a postfix operator.
+/// Operators in the precedence table are not, so that `a - -b` is a subtraction.
+pub fn is_postfix_operator(operator: &str) -> bool {
+    !is_reserved_operator(operator) && get_standard_precedence(operator).is_none()
+}
+
+/// Checks if an operator or keyword operator can be used as an infix operator.
+pub fn is_infix_operator(operator: &str) -> bool {
+    operator != "not" && !is_reserved_operator(operator)
+}
+
+/************************* INFIX EXPRESSION *************************/
+
+/// The parts of an infix expression in the order they appear in code.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InfixItem {
+    Operand(SimpleExpr),
+    Not, // A prefix `not` operator.
+    Operator(String),
+}
+
+/// Folds the parts of an infix expression into a tree of binary and unary operations
+/// according to the precedence and associativity of its operators.
+pub fn fold_infix_expression(items: Vec<InfixItem>) -> SimpleExpr {
+    fold_infix_items(&mut items.into_iter().peekable(), 0)
+}
+
+/// Folds the parts of an infix expression, stopping at the first operator
+/// that binds looser than the minimum precedence.
+fn fold_infix_items(items: &mut Peekable<IntoIter<InfixItem>>, min_precedence: u8) -> SimpleExpr {
+    // Get the left operand.
+    let mut lhs = match items.next() {
+        Some(InfixItem::Operand(operand)) => operand,
+        Some(InfixItem::Not) => SimpleExpr::UnaryOp {
+            op: "not".into(),
+            fixity: Fixity::Prefix,
+            expr: Box::new(fold_infix_items(items, NOT_PRECEDENCE)),
+        },
+        _ => unreachable!("infix expression must have an operand where an operator is"),
+    };
+
+    // Fold operators that bind at least as tight as the minimum precedence.
+    while let Some(InfixItem::Operator(operator)) = items.peek() {
+        let (precedence, associativity) = get_infix_precedence(operator);
+        if precedence < min_precedence {
+            break;
+        }
+
+        // Get the operator.
+        let operator = match items.next() {
+            Some(InfixItem::Operator(operator)) => operator,
+            _ => unreachable!(),
+        };
+
+        // Operators of the same precedence only bind to the right operand if right associative.
+        let next_min_precedence = match associativity {
+            Associativity::Left => precedence + 1,
+            Associativity::Right => precedence,
+        };
+
+        // Get the right operand.
+        let rhs = fold_infix_items(items, next_min_precedence);
+
+        lhs = SimpleExpr::BinaryOp {
+            op: operator,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+    }
+
+    lhs
+}
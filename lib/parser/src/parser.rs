@@ -3,10 +3,15 @@ use crate::{
     errors::ParserError,
     kinds::ErrorKind,
     macros,
-    utils::get_func_addr,
+    operators::{self, InfixItem},
 };
-use astro_codegen::asts::{SimpleExpr, AST};
-use astro_lexer::{Token, TokenKind};
+use astro_codegen::asts::{
+    Assign, Binding, BindingKind, CallArg, Closure, Compr, ComprKind, Condition, ContainerPat,
+    EnumDecl, EnumVariant, Expr, Field, Fixity, For, FunctionDecl, GenericParam, If, Iter, IterArg,
+    Match, MatchArm, MatchContainerPat, MatchPat, MatchPatArg, Object, Param, Pat, PatArg, PatKind,
+    Range, SimpleExpr, TypeDecl, TypeExpr, TypeRelation, While, AST,
+};
+use astro_lexer::{SourceMap, Span, Token, TokenKind};
 
 /************************* PARSER *************************/
 
@@ -15,11 +20,6 @@ pub struct Parser {
     combinator: Combinator<AST>,
 }
 
-type ParserFn<'a> = fn(
-    args: &[CombinatorArg<'a, AST>],
-    combinator: &mut Combinator<AST>,
-) -> Result<Output<AST>, ParserError>;
-
 impl Parser {
     /// Creates a new parser object from the tokens passed in.
     pub fn new(tokens: Vec<Token>) -> Self {
@@ -28,41 +28,342 @@ impl Parser {
         }
     }
 
+    /// Creates a new parser object from the tokens passed in and the source map
+    /// of the code they come from, which gives exact positions at the end of the code.
+    pub fn with_source_map(tokens: Vec<Token>, source_map: SourceMap) -> Self {
+        Self {
+            combinator: Combinator::with_source_map(tokens, source_map),
+        }
+    }
+
     /// Takes and parses valid tokens from Astro code.
     pub fn parse(&mut self) -> Result<AST, ParserError> {
         // The combinator to use.
         let combinator = &mut self.combinator;
 
-        println!("===== parser starts =====");
+        // Get parser result.
+        let combinator_result = parse!(combinator, f!(program))?;
+
+        // Check that all the tokens have been consumed.
+        if combinator.is_inbounds() {
+            // Report what was expected where parsing got the furthest.
+            if combinator.get_furthest_cursor() >= combinator.get_cursor() {
+                return Err(combinator.get_furthest_error(ErrorKind::IncompleteParse));
+            }
+
+            let span = combinator.get_span();
+            return Err(ParserError::new(ErrorKind::IncompleteParse, span));
+        }
+
+        // Pull array out of Output::Values.
+        let mut values = variant_value!(combinator_result, Output::Values);
+
+        // Pull AST::Program out of Output::AST.
+        Ok(variant_value!(values.remove(0), Output::AST))
+    }
+
+    /// Takes and parses valid tokens from Astro code, recovering from errors.
+    /// A statement that fails to parse is skipped up to the next synchronization token
+    /// and replaced with an error node, so every error in the code gets reported.
+    /// Statements in blocks and fields in type bodies are recovered from the same way.
+    pub fn parse_recovering(&mut self) -> (AST, Vec<ParserError>) {
+        // The combinator to use.
+        let combinator = &mut self.combinator;
+
+        let expressions = Parser::parse_statements(combinator, false);
+
+        (AST::Program(expressions), combinator.take_errors())
+    }
+
+    /// Parses statements until the tokens run out or, in a block, until its closing `}`.
+    /// Errors are recorded in the combinator and each statement that fails to parse is
+    /// skipped up to the next synchronization token and replaced with an error node.
+    /// A failing statement outside of a block is parsed again in recovering mode,
+    /// so that errors in the blocks it contains are recovered from the same way.
+    fn parse_statements(combinator: &mut Combinator<AST>, is_block: bool) -> Vec<AST> {
+        // Holds statements.
+        let mut expressions = vec![];
+
+        loop {
+            // Skip statement separators.
+            parse!(combinator, optmore!(alt!(f!(newline), s!(";")))).unwrap();
+
+            // Check if all the tokens have been consumed.
+            if !combinator.is_inbounds() {
+                break;
+            }
+
+            // Only report what this statement expected.
+            combinator.clear_expected();
+
+            // Check for closing brackets in a block.
+            if is_block {
+                match Parser::get_closing_bracket(combinator).as_deref() {
+                    // The block ends.
+                    Some("}") => break,
+                    // The bracket is not closing anything.
+                    Some(_) => {
+                        combinator.expect("`}`".into());
+                        let error = combinator.get_furthest_error(ErrorKind::UnexpectedToken);
+                        let error_cursor = combinator.get_cursor();
+                        combinator.add_error(error);
+                        expressions.push(AST::Error(Parser::synchronize(
+                            combinator,
+                            error_cursor,
+                            true,
+                            false,
+                        )));
+                        continue;
+                    }
+                    None => (),
+                }
+            }
+
+            // Get parser result.
+            let cursor = combinator.get_cursor();
+            let mut parser_result = parse!(combinator, f!(block_expression));
+
+            // Report what was expected where parsing got the furthest.
+            let mut error = match &parser_result {
+                Ok(_) if Parser::is_statement_end(combinator, is_block) => None,
+                // A statement must be followed by a separator or the end of its block.
+                Ok(_) => {
+                    combinator.expect(TokenKind::Newline.to_string());
+                    combinator.expect("`;`".into());
+                    if is_block {
+                        combinator.expect("`}`".into());
+                    }
+                    Some(combinator.get_furthest_error(ErrorKind::UnexpectedToken))
+                }
+                Err(error) => Some(combinator.get_furthest_error(error.error.clone())),
+            };
+            let error_cursor = combinator.get_furthest_cursor();
+
+            // Parse the statement again, recovering from errors in its blocks.
+            if error.is_some() && !combinator.is_recovering() {
+                let error_count = combinator.get_error_count();
+
+                combinator.set_cursor(cursor);
+                combinator.set_recovering(true);
+                let recovered_result = parse!(combinator, f!(block_expression));
+                let is_recovered =
+                    recovered_result.is_ok() && Parser::is_statement_end(combinator, is_block);
+                combinator.set_recovering(false);
+
+                if is_recovered {
+                    parser_result = recovered_result;
+                    error = None;
+                } else {
+                    // Drop errors of blocks that did not make up the statement.
+                    combinator.truncate_errors(error_count);
+                }
+            }
+
+            // Skip the whole statement up to the next one.
+            if let Some(error) = error {
+                combinator.set_cursor(cursor);
+                combinator.add_error(error);
+                expressions.push(AST::Error(Parser::synchronize(
+                    combinator,
+                    error_cursor,
+                    is_block,
+                    false,
+                )));
+                continue;
+            }
+
+            // Pull array out of Output::Values.
+            let mut values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            expressions.push(variant_value!(values.remove(0), Output::AST));
+        }
+
+        expressions
+    }
+
+    /// Parses the fields of a type body until its closing `}`.
+    /// Errors are recorded in the combinator and each field that fails to parse is
+    /// skipped up to the next synchronization token and left out.
+    fn parse_fields(combinator: &mut Combinator<AST>) -> Vec<Field> {
+        // Holds fields.
+        let mut fields = vec![];
+
+        loop {
+            // Skip field separators.
+            parse!(combinator, optmore!(alt!(f!(newline), s!(",")))).unwrap();
+
+            // Check if the type body ends.
+            if !combinator.is_inbounds()
+                || Parser::get_closing_bracket(combinator).as_deref() == Some("}")
+            {
+                break;
+            }
+
+            // Only report what this field expected.
+            combinator.clear_expected();
+
+            // Get parser result.
+            let cursor = combinator.get_cursor();
+            let parser_result = parse!(combinator, f!(type_field));
+
+            // Report what was expected where parsing got the furthest.
+            let error = match &parser_result {
+                // A field must be followed by a comma or the end of the type body.
+                Ok(_) => {
+                    let field_end = combinator.get_cursor();
+                    let parser_result =
+                        parse!(combinator, opt!(f!(newlines)), alt!(s!(","), s!("}")));
+                    combinator.set_cursor(field_end);
+
+                    parser_result
+                        .err()
+                        .map(|_| combinator.get_furthest_error(ErrorKind::UnexpectedToken))
+                }
+                Err(error) => Some(combinator.get_furthest_error(error.error.clone())),
+            };
+            let error_cursor = combinator.get_furthest_cursor();
+
+            // Skip the whole field up to the next one.
+            if let Some(error) = error {
+                combinator.set_cursor(cursor);
+                combinator.add_error(error);
+                Parser::synchronize(combinator, error_cursor, true, true);
+                continue;
+            }
+
+            // Pull array out of Output::Values.
+            let mut values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Field out of Output::AST.
+            let ast = variant_value!(values.remove(0), Output::AST);
+            fields.push(*variant_value!(ast, AST::Field));
+        }
+
+        fields
+    }
+
+    /// Checks if a statement ends at the cursor, i.e. if a separator, the end of the tokens
+    /// or, in a block, its closing `}` is next.
+    fn is_statement_end(combinator: &mut Combinator<AST>, is_block: bool) -> bool {
+        Parser::is_separator_next(combinator)
+            || (is_block && Parser::get_closing_bracket(combinator).as_deref() == Some("}"))
+    }
+
+    /// Gets the next token if it is a closing bracket.
+    fn get_closing_bracket(combinator: &mut Combinator<AST>) -> Option<String> {
+        combinator
+            .peek_token()
+            .filter(|token| token.kind == TokenKind::Punctuator)
+            .and_then(|token| token.token)
+            .filter(|word| [")", "]", "}"].contains(&word.as_str()))
+    }
+
+    /// Checks if the next token separates statements or if there are no more tokens.
+    fn is_separator_next(combinator: &mut Combinator<AST>) -> bool {
+        match combinator.peek_token() {
+            Some(token) => {
+                token.kind == TokenKind::Newline
+                    || (token.kind == TokenKind::Punctuator && token.token == Some(";".into()))
+            }
+            None => true,
+        }
+    }
+
+    /// Skips tokens until a synchronization token is next, i.e. a newline at statement level,
+    /// an unmatched closing bracket, or a keyword that begins a declaration.
+    /// In a type body, a comma at field level is a synchronization token too.
+    /// A closing bracket the error was found at is skipped as well, unless it closes the body
+    /// being parsed. Returns the span of the skipped tokens.
+    fn synchronize(
+        combinator: &mut Combinator<AST>,
+        error_cursor: usize,
+        is_body: bool,
+        is_field: bool,
+    ) -> Span {
+        // Get the span of the first token to skip.
+        let start = combinator.get_span();
+        let mut end = start;
+
+        // Holds the brackets opened while skipping.
+        let mut brackets: Vec<String> = vec![];
+        let mut skipped = 0;
+
+        while let Some(token) = combinator.peek_token() {
+            let word = token.token.clone().unwrap_or_default();
+            let is_punctuator = token.kind == TokenKind::Punctuator;
+
+            // Get the opening bracket if token is a closing bracket.
+            let opening_bracket = match word.as_str() {
+                ")" if is_punctuator => Some("("),
+                "]" if is_punctuator => Some("["),
+                "}" if is_punctuator => Some("{"),
+                _ => None,
+            };
+
+            // Always skip the first token so that progress is made.
+            if skipped > 0 {
+                // Stop at newlines at statement level.
+                if token.kind == TokenKind::Newline && brackets.is_empty() {
+                    break;
+                }
+
+                // Stop at commas at field level.
+                if is_field && is_punctuator && word == "," && brackets.is_empty() {
+                    break;
+                }
+
+                // Stop at closing brackets that were not opened while skipping.
+                if opening_bracket.is_some() && brackets.is_empty() {
+                    let is_body_end = is_body && word == "}";
+                    if combinator.get_cursor() != error_cursor || is_body_end {
+                        break;
+                    }
+                }
 
-        // let combinator_result = alt!(combinator, parse!(f!(integer_literal)));
-        // let combinator_result = parse!(combinator, f!(list_arguments));
-        // let combinator_result = parse!(combinator, f!(tuple_arguments));
-        let combinator_result = parse!(combinator, f!(tuple_literal));
+                // Stop at keywords that begin a declaration.
+                if token.kind == TokenKind::Keyword
+                    && ["fun", "type", "enum", "import"].contains(&word.as_str())
+                {
+                    break;
+                }
+            }
 
-        println!("===== cache ===== \n{}", combinator.get_cache_string());
+            // Keep track of brackets.
+            if is_punctuator && ["(", "[", "{"].contains(&word.as_str()) {
+                brackets.push(word);
+            } else if let Some(opening_bracket) = opening_bracket {
+                // Also close brackets left unclosed within the matching pair.
+                if let Some(index) = brackets.iter().rposition(|x| x == opening_bracket) {
+                    brackets.truncate(index);
+                }
+            }
 
-        println!("===== parser result ===== \n{:#?}", combinator_result);
+            // Skip the token.
+            end = token.span;
+            combinator.eat_token().unwrap();
+            skipped += 1;
+        }
 
-        unimplemented!()
+        Span::new(start.start, end.end, start.line, start.column)
     }
 
     /// Parses a terminal, i.e. the different types of token that make up the parser's productions.
-    pub fn parse_terminal<'a>(
+    pub fn parse_terminal(
         kind: TokenKind,
         combinator: &mut Combinator<AST>,
-        func: ParserFn<'a>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and columns.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::UnexpectedToken, column));
+            Err(ParserError::new(ErrorKind::UnexpectedToken, span));
 
         // Get the next token.
-        let token = combinator.eat_token()?;
+        let token = combinator
+            .eat_token()
+            .inspect_err(|_| combinator.expect(kind.to_string()))?;
 
         // Check if the token kind is the same as the one provided.
         if token.kind == kind {
@@ -73,20 +374,167 @@ impl Parser {
         } else {
             // Revert advancement.
             combinator.set_cursor(cursor);
+            combinator.expect(kind.to_string());
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&func), result.clone());
-
         result
     }
 
+    /// Checks if the next token comes after a space or not without consuming it.
+    pub fn parse_space(
+        space_before: bool,
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the span.
+        let span = combinator.get_span();
+
+        // Check the next token.
+        match combinator.peek_token() {
+            Some(token) if token.space_before == space_before => Ok(Output::AST(AST::Empty)),
+            _ if space_before => Err(ParserError::new(ErrorKind::ExpectedSpace, span)),
+            _ => Err(ParserError::new(ErrorKind::ExpectedNoSpace, span)),
+        }
+    }
+
+    /// Gets the value of a terminal.
+    fn get_terminal_value(output: Output<AST>) -> String {
+        // Pull AST::SimpleExpr out of Output::AST.
+        let ast_expr = variant_value!(output, Output::AST);
+
+        // Pull SimpleExpr::Terminal out of AST::SimpleExpr.
+        let simple_expr = variant_value!(ast_expr, AST::SimpleExpr);
+
+        // Pull value field out of SimpleExpr::Terminal.
+        let (_, value) = variant_fields!(simple_expr, SimpleExpr::Terminal, { kind, value });
+
+        value
+    }
+
+    /// Fills in the expression of a postfix returned by postfix rules.
+    fn apply_postfix(expr: SimpleExpr, postfix: SimpleExpr) -> SimpleExpr {
+        let expr = Box::new(expr);
+        match postfix {
+            SimpleExpr::Call {
+                vectorized,
+                type_args,
+                args,
+                ..
+            } => SimpleExpr::Call {
+                expr,
+                vectorized,
+                type_args,
+                args,
+            },
+            SimpleExpr::Dot { name, .. } => SimpleExpr::Dot { expr, name },
+            SimpleExpr::Index { args, .. } => SimpleExpr::Index { expr, args },
+            SimpleExpr::TupleIndex { index, .. } => SimpleExpr::TupleIndex { expr, index },
+            SimpleExpr::Unwrap(_) => SimpleExpr::Unwrap(expr),
+            _ => unreachable!("Entered the wrong variant"),
+        }
+    }
+
+    /// Flags a rule that parses an expression followed by a block, e.g. a condition.
+    /// The expression leaves out object literals because the `{` after it starts the block.
+    const BEFORE_BLOCK: &'static str = "before_block";
+
+    /// Checks if a rule parses an expression followed by a block.
+    fn is_before_block(args: &[CombinatorArg<AST>]) -> bool {
+        args.iter()
+            .any(|arg| matches!(arg, CombinatorArg::Flag(flag) if *flag == Parser::BEFORE_BLOCK))
+    }
+
+    /// Gets the arguments that pass on the flag of a rule followed by a block to its subrules.
+    fn get_before_block_args<'a>(args: &[CombinatorArg<AST>]) -> Vec<CombinatorArg<'a, AST>> {
+        match Parser::is_before_block(args) {
+            true => vec![CombinatorArg::Flag(Parser::BEFORE_BLOCK)],
+            false => vec![],
+        }
+    }
+
+    /// Gets the expression out of an optional Output::AST.
+    fn get_optional_expr(output: Output<AST>) -> Option<SimpleExpr> {
+        match output {
+            Output::Empty => None,
+            _ => {
+                let ast = variant_value!(
+                    variant_value!(output, Output::Values).remove(0),
+                    Output::AST
+                );
+                Some(variant_value!(ast, AST::SimpleExpr))
+            }
+        }
+    }
+
+    /// Gets a range from the outputs of `start? '..' (step? '..')? end?`.
+    fn get_range(mut values: Vec<Output<AST>>) -> SimpleExpr {
+        // Pulls SimpleExpr::* out of an optional Output::AST.
+        let get_bound = |output: Output<AST>| match output {
+            Output::Empty => None,
+            _ => {
+                let ast = variant_value!(
+                    variant_value!(output, Output::Values).remove(0),
+                    Output::AST
+                );
+                Some(Box::new(variant_value!(ast, AST::SimpleExpr)))
+            }
+        };
+
+        let start = get_bound(values.remove(0));
+
+        // A middle expression is the step and the last is the end.
+        let step = match values.remove(1) {
+            Output::Empty => None,
+            output => get_bound(variant_value!(output, Output::Values).remove(0)),
+        };
+        let end = get_bound(values.remove(1));
+
+        SimpleExpr::Range(Range { start, step, end })
+    }
+
+    /// Fills in the type of a postfix returned by typepostfix.
+    fn apply_type_postfix(type_expr: TypeExpr, postfix: TypeExpr) -> TypeExpr {
+        let type_expr = Box::new(type_expr);
+        match postfix {
+            TypeExpr::Dot(_, name) => TypeExpr::Dot(type_expr, name),
+            TypeExpr::Generic(_, args) => TypeExpr::Generic(type_expr, args),
+            _ => unreachable!("Entered the wrong variant"),
+        }
+    }
+
+    /// Gets the parameters out of an optional functionarguments output.
+    fn get_params(output: Output<AST>) -> Vec<Param> {
+        match output {
+            Output::Empty => vec![],
+            _ => {
+                let ast = variant_value!(
+                    variant_value!(output, Output::Values).remove(0),
+                    Output::AST
+                );
+                variant_value!(variant_value!(ast, AST::SimpleExpr), SimpleExpr::Function).params
+            }
+        }
+    }
+
+    /// Gets the block out of an optional expressions output.
+    fn get_body(output: Output<AST>) -> Vec<AST> {
+        match output {
+            Output::Empty => vec![],
+            _ => {
+                let ast = variant_value!(
+                    variant_value!(output, Output::Values).remove(0),
+                    Output::AST
+                );
+                variant_value!(ast, AST::Block)
+            }
+        }
+    }
+
     /// Parses newline.
     pub fn newline<'a>(
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Newline, combinator, Parser::newline as _)
+        Parser::parse_terminal(TokenKind::Newline, combinator)
     }
 
     /// Parses no_name.
@@ -94,7 +542,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Placeholder, combinator, Parser::no_name)
+        Parser::parse_terminal(TokenKind::Placeholder, combinator)
     }
 
     /// Parses identifier.
@@ -102,7 +550,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Identifier, combinator, Parser::identifier)
+        Parser::parse_terminal(TokenKind::Identifier, combinator)
     }
 
     /// Parses boolean literal.
@@ -110,11 +558,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::BooleanLiteral,
-            combinator,
-            Parser::boolean_literal,
-        )
+        Parser::parse_terminal(TokenKind::BooleanLiteral, combinator)
     }
 
     /// Parses keyword.
@@ -122,7 +566,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Keyword, combinator, Parser::keyword)
+        Parser::parse_terminal(TokenKind::Keyword, combinator)
     }
 
     /// Parses operator.
@@ -130,7 +574,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Operator, combinator, Parser::operator)
+        Parser::parse_terminal(TokenKind::Operator, combinator)
     }
 
     /// Parses punctuator.
@@ -138,7 +582,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::Punctuator, combinator, Parser::punctuator)
+        Parser::parse_terminal(TokenKind::Punctuator, combinator)
     }
 
     /// Parses integer binary literal.
@@ -146,11 +590,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::IntegerBinaryLiteral,
-            combinator,
-            Parser::integer_binary_literal,
-        )
+        Parser::parse_terminal(TokenKind::IntegerBinaryLiteral, combinator)
     }
 
     /// Parses integer octal literal.
@@ -158,11 +598,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::IntegerOctalLiteral,
-            combinator,
-            Parser::integer_octal_literal,
-        )
+        Parser::parse_terminal(TokenKind::IntegerOctalLiteral, combinator)
     }
 
     /// Parses integer hexadecimal literal.
@@ -170,11 +606,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::IntegerHexadecimalLiteral,
-            combinator,
-            Parser::integer_hexadecimal_literal,
-        )
+        Parser::parse_terminal(TokenKind::IntegerHexadecimalLiteral, combinator)
     }
 
     /// Parses integer decimal literal.
@@ -182,11 +614,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::IntegerDecimalLiteral,
-            combinator,
-            Parser::integer_decimal_literal,
-        )
+        Parser::parse_terminal(TokenKind::IntegerDecimalLiteral, combinator)
     }
 
     /// Parses float binary literal.
@@ -194,11 +622,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::FloatBinaryLiteral,
-            combinator,
-            Parser::float_binary_literal,
-        )
+        Parser::parse_terminal(TokenKind::FloatBinaryLiteral, combinator)
     }
 
     /// Parses float octal literal.
@@ -206,11 +630,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::FloatOctalLiteral,
-            combinator,
-            Parser::float_octal_literal,
-        )
+        Parser::parse_terminal(TokenKind::FloatOctalLiteral, combinator)
     }
 
     /// Parses float hexadecimal literal.
@@ -218,11 +638,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::FloatHexadecimalLiteral,
-            combinator,
-            Parser::float_hexadecimal_literal,
-        )
+        Parser::parse_terminal(TokenKind::FloatHexadecimalLiteral, combinator)
     }
 
     /// Parses float decimal literal.
@@ -230,11 +646,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(
-            TokenKind::FloatDecimalLiteral,
-            combinator,
-            Parser::float_decimal_literal,
-        )
+        Parser::parse_terminal(TokenKind::FloatDecimalLiteral, combinator)
     }
 
     /// Parses char literal.
@@ -242,7 +654,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::CharLiteral, combinator, Parser::char_literal)
+        Parser::parse_terminal(TokenKind::CharLiteral, combinator)
     }
 
     /// Parses regex literal.
@@ -250,7 +662,7 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::RegexLiteral, combinator, Parser::regex_literal)
+        Parser::parse_terminal(TokenKind::RegexLiteral, combinator)
     }
 
     /// Parses string literal.
@@ -258,7 +670,57 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        Parser::parse_terminal(TokenKind::StringLiteral, combinator, Parser::string_literal)
+        Parser::parse_terminal(TokenKind::StringLiteral, combinator)
+    }
+
+    /// Parses symbolliteral =
+    ///     | ':' NOSPACE identifier
+    pub fn symbol_literal<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedSymbolLiteral, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, s!(":"), f!(no_space), f!(identifier));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the name in the third Output::AST.
+            let name = Parser::get_terminal_value(parser_result_values.remove(2));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Symbol(name))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses HASSPACE, i.e. checks that the next token comes after a space.
+    pub fn has_space<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        Parser::parse_space(true, combinator)
+    }
+
+    /// Parses NOSPACE, i.e. checks that the next token comes right after the previous one.
+    pub fn no_space<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        Parser::parse_space(false, combinator)
     }
 
     /// Parses integer literal.
@@ -266,13 +728,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedIntegerLiteral, column));
+            Err(ParserError::new(ErrorKind::ExpectedIntegerLiteral, span));
 
         // Get parser result.
         let parser_result = alt!(
@@ -292,15 +754,11 @@ impl Parser {
             let mut array = variant_value!(*value, Output::Values);
 
             result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(
-            cursor,
-            get_func_addr(&(Parser::integer_literal as _)),
-            result.clone(),
-        );
-
         result
     }
 
@@ -309,13 +767,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedFloatLiteral, column));
+            Err(ParserError::new(ErrorKind::ExpectedFloatLiteral, span));
 
         // Get parser result.
         let parser_result = alt!(
@@ -340,13 +798,6 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(
-            cursor,
-            get_func_addr(&(Parser::float_literal as _)),
-            result.clone(),
-        );
-
         result
     }
 
@@ -355,13 +806,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedFloatLiteral, column));
+            Err(ParserError::new(ErrorKind::ExpectedFloatLiteral, span));
 
         // Get parser result.
         let parser_result = alt!(combinator, f!(float_literal), f!(integer_literal));
@@ -380,13 +831,6 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(
-            cursor,
-            get_func_addr(&(Parser::numeric_literal as _)),
-            result.clone(),
-        );
-
         result
     }
 
@@ -396,13 +840,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedNewlines, column));
+            Err(ParserError::new(ErrorKind::ExpectedNewlines, span));
 
         // Get parser result.
         let parser_result = parse!(combinator, more!(f!(newline)));
@@ -415,9 +859,6 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
-
         result
     }
 
@@ -427,13 +868,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedComma, column));
+            Err(ParserError::new(ErrorKind::ExpectedComma, span));
 
         // Get parser result.
         let parser_result = parse!(combinator, opt!(f!(newlines)), s!(","), opt!(f!(newlines)));
@@ -446,32 +887,56 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
+        result
+    }
+
+    /// Parses dot =
+    ///     | newlines? '.'
+    pub fn dot<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedDot, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, opt!(f!(newlines)), s!("."));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            result = Ok(Output::AST(AST::Empty));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
 
         result
     }
 
-    /// TODO: Change numericliteral to simpleexpression.
     /// Parses listarguments =
     ///     | simpleexpression (comma simpleexpression)* comma?
     pub fn list_arguments<'a>(
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedListArguments, column));
+            Err(ParserError::new(ErrorKind::ExpectedListArguments, span));
 
         // Get parser result.
         let parser_result = parse!(
             combinator,
-            f!(numeric_literal),
-            optmore!(f!(comma), f!(numeric_literal)),
+            f!(simple_expression),
+            optmore!(f!(comma), f!(simple_expression)),
             opt!(f!(comma))
         );
 
@@ -526,9 +991,6 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
-
         result
     }
 
@@ -538,13 +1000,13 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedListLiteral, column));
+            Err(ParserError::new(ErrorKind::ExpectedListLiteral, span));
 
         // Get parser result.
         let parser_result = parse!(
@@ -577,13 +1039,9 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
-
         result
     }
 
-    /// TODO: Change numericliteral to simpleexpression.
     /// Parses tuplearguments =
     ///     | simpleexpression (comma simpleexpression)+ comma?
     ///     | simpleexpression comma
@@ -591,23 +1049,23 @@ impl Parser {
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedTupleArguments, column));
+            Err(ParserError::new(ErrorKind::ExpectedTupleArguments, span));
 
         // Get parser result.
         let parser_result = alt!(
             combinator,
             parse!(
-                f!(numeric_literal),
-                more!(f!(comma), f!(numeric_literal)),
+                f!(simple_expression),
+                more!(f!(comma), f!(simple_expression)),
                 opt!(f!(comma))
             ),
-            parse!(f!(numeric_literal), f!(comma))
+            parse!(f!(simple_expression), f!(comma))
         );
 
         // Holds expressions.
@@ -679,25 +1137,22 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
-
         result
     }
 
     /// Parses tupleliteral =
-    ///     | '(' newlines? listarguments? newlines? ')'
+    ///     | '(' newlines? tuplearguments? newlines? ')'
     pub fn tuple_literal<'a>(
         _args: &[CombinatorArg<'a, AST>],
         combinator: &mut Combinator<AST>,
     ) -> Result<Output<AST>, ParserError> {
-        // Get the cursor and column.
+        // Get the cursor and span.
         let cursor = combinator.get_cursor();
-        let column = combinator.get_column();
+        let span = combinator.get_span();
 
         // Holds the returning result.
         let mut result: Result<Output<AST>, ParserError> =
-            Err(ParserError::new(ErrorKind::ExpectedTupleLiteral, column));
+            Err(ParserError::new(ErrorKind::ExpectedTupleLiteral, span));
 
         // Get parser result.
         let parser_result = parse!(
@@ -709,8 +1164,6 @@ impl Parser {
             s!(")")
         );
 
-        println!("parser_result = {:#?}", parser_result);
-
         // Check if parser result is OK.
         if parser_result.is_ok() {
             // Pull array out of Output::Values.
@@ -732,8 +1185,7219 @@ impl Parser {
             combinator.set_cursor(cursor);
         }
 
-        // Cache parser result if not already cached.
-        combinator.memoize(cursor, get_func_addr(&(Parser::comma as _)), result.clone());
+        result
+    }
+
+    /// Parses dictargument =
+    ///     | simpleexpression newlines? ':' newlines? simpleexpression
+    ///     | simpleexpression
+    pub fn dict_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedDictArgument, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                f!(simple_expression),
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(simple_expression)
+            ),
+            f!(simple_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let entry = match index {
+                // Key and value.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Pull SimpleExpr::* out of the first and fifth Output::AST.
+                    let key = variant_value!(
+                        variant_value!(values.remove(0), Output::AST),
+                        AST::SimpleExpr
+                    );
+                    let value = variant_value!(
+                        variant_value!(values.remove(3), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    (key, Some(value))
+                }
+                // Shorthand key.
+                _ => {
+                    // Pull SimpleExpr::* out of Output::AST.
+                    let key = variant_value!(variant_value!(value, Output::AST), AST::SimpleExpr);
+
+                    (key, None)
+                }
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Dict(vec![entry]))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses dictarguments =
+    ///     | dictargument (comma dictargument)* comma?
+    pub fn dict_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedDictArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(dict_argument),
+            optmore!(f!(comma), f!(dict_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds entries.
+        let mut entries = vec![];
+
+        // Pulls the entries out of a dict argument's AST::SimpleExpr.
+        let get_entries = |output: Output<AST>| {
+            variant_value!(
+                variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr),
+                SimpleExpr::Dict
+            )
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first entry.
+            entries.extend(get_entries(parser_result_values.remove(0)));
+
+            // Get subsequent entries.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the entry out of the second Output::AST.
+                    entries.extend(get_entries(values.remove(1)));
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Dict(entries))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses dictliteral =
+    ///     | '{' newlines? dictarguments? newlines? '}'
+    pub fn dict_literal<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedDictLiteral, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("{"),
+            opt!(f!(newlines)),
+            opt!(f!(dict_arguments)),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the third element.
+            let value = parser_result_values.remove(2);
+
+            let dict_expr = match value {
+                // Create an empty dict if there is nothing in the third element.
+                Output::Empty => Output::AST(AST::SimpleExpr(SimpleExpr::Dict(vec![]))),
+                // Otherwise Pull an array out of Output::Values and get first element.
+                _ => variant_value!(value, Output::Values).remove(0),
+            };
+
+            result = Ok(dict_expr);
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses objectargument =
+    ///     | identifier newlines? ':' newlines? simpleexpression
+    ///     | identifier
+    pub fn object_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedObjectArgument, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                f!(identifier),
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(simple_expression)
+            ),
+            f!(identifier)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let field = match index {
+                // Field and value.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Get the field name and pull SimpleExpr::* out of the fifth Output::AST.
+                    let name = Parser::get_terminal_value(values.remove(0));
+                    let value = variant_value!(
+                        variant_value!(values.remove(3), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    (name, Some(value))
+                }
+                // Shorthand field.
+                _ => (Parser::get_terminal_value(value), None),
+            };
+
+            // The type name is added by objectliteral.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Object(Object {
+                name: String::new(),
+                type_args: vec![],
+                fields: vec![field],
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses objectarguments =
+    ///     | objectargument (comma objectargument)* comma?
+    pub fn object_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedObjectArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(object_argument),
+            optmore!(f!(comma), f!(object_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds fields.
+        let mut fields = vec![];
+
+        // Pulls the fields out of an object argument's AST::SimpleExpr.
+        let get_fields = |output: Output<AST>| {
+            variant_value!(
+                variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr),
+                SimpleExpr::Object
+            )
+            .fields
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first field.
+            fields.extend(get_fields(parser_result_values.remove(0)));
+
+            // Get subsequent fields.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the field out of the second Output::AST.
+                    fields.extend(get_fields(values.remove(1)));
+                }
+            }
+
+            // The type name is added by objectliteral.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Object(Object {
+                name: String::new(),
+                type_args: vec![],
+                fields,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses objectliteral =
+    ///     | identifier ('[' newlines? typefunctionarguments newlines? ']')? '{' newlines? objectarguments? newlines? '}'
+    pub fn object_literal<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedObjectLiteral, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(identifier),
+            opt!(
+                s!("["),
+                opt!(f!(newlines)),
+                f!(type_function_arguments),
+                opt!(f!(newlines)),
+                s!("]")
+            ),
+            s!("{"),
+            opt!(f!(newlines)),
+            opt!(f!(object_arguments)),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the type name.
+            let name = Parser::get_terminal_value(parser_result_values.remove(0));
+
+            // Get the type arguments if they exist.
+            let value = parser_result_values.remove(0);
+            let type_args = match value {
+                Output::Empty => vec![],
+                _ => {
+                    // Pull TypeExpr::Tuple out of the third Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(value, Output::Values).remove(2),
+                        Output::AST
+                    );
+                    variant_value!(variant_value!(ast, AST::TypeExpr), TypeExpr::Tuple)
+                }
+            };
+
+            // Get the fields if they exist.
+            let value = parser_result_values.remove(2);
+            let fields = match value {
+                Output::Empty => vec![],
+                _ => {
+                    // Pull SimpleExpr::Object out of Output::Values.
+                    let ast = variant_value!(
+                        variant_value!(value, Output::Values).remove(0),
+                        Output::AST
+                    );
+                    variant_value!(variant_value!(ast, AST::SimpleExpr), SimpleExpr::Object).fields
+                }
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Object(Object {
+                name,
+                type_args,
+                fields,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses containers =
+    ///     | listliteral
+    ///     | tupleliteral
+    ///     | dictliteral
+    ///     | objectliteral
+    /// Object literals are left out of conditions.
+    pub fn containers<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedContainer, span));
+
+        // Get parser result.
+        let parser_result = if Parser::is_before_block(args) {
+            alt!(
+                combinator,
+                f!(list_literal),
+                f!(tuple_literal),
+                f!(dict_literal)
+            )
+        } else {
+            alt!(
+                combinator,
+                f!(list_literal),
+                f!(tuple_literal),
+                f!(dict_literal),
+                f!(object_literal)
+            )
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses literal =
+    ///     | numericliteral
+    ///     | booleanliteral
+    ///     | charliteral
+    ///     | stringliteral
+    ///     | regexliteral
+    ///     | containers
+    pub fn literal<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLiteral, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let containers = CombinatorArg::Rule(("containers", Parser::containers as _, &block_args));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(numeric_literal),
+            f!(boolean_literal),
+            f!(char_literal),
+            f!(string_literal),
+            f!(regex_literal),
+            containers
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses comprehensionexpression =
+    ///     | infixexpression
+    /// `|` is not parsed as an infix operator here because it separates the comprehension's iteration.
+    pub fn comprehension_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedComprehensionExpression,
+            span,
+        ));
+
+        // Get parser result.
+        // Uses its own rule id so it is not cached as a regular infixexpression.
+        let parser_result = parse!(
+            combinator,
+            CombinatorArg::Rule((
+                "comprehension_infix_expression",
+                Parser::infix_expression as _,
+                &vec![s!("|")],
+            ))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            result = Ok(parser_result_values.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses iterexpression =
+    ///     | infixexpression? '..' (infixexpression? '..')? infixexpression?
+    ///     | infixexpression
+    /// `and` is not parsed as an infix operator here because it separates iteration arguments.
+    pub fn iter_expression<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIterExpression, span));
+
+        // Infix expressions without `and`.
+        // Uses its own rule id so it is not cached as a regular infixexpression.
+        let mut excluded_operators = vec![s!("and")];
+        excluded_operators.extend(Parser::get_before_block_args(args));
+        let operand = || {
+            CombinatorArg::Rule((
+                "iter_infix_expression",
+                Parser::infix_expression as _,
+                &excluded_operators,
+            ))
+        };
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                opt!(operand()),
+                s!(".."),
+                opt!(opt!(operand()), s!("..")),
+                opt!(operand())
+            ),
+            operand()
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            result = match index {
+                // Range.
+                0 => Ok(Output::AST(AST::SimpleExpr(Parser::get_range(
+                    variant_value!(value, Output::Values),
+                )))),
+                _ => Ok(value),
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses iterargument =
+    ///     | 'var'? newlines? lhspattern newlines? 'in' newlines? iterexpression
+    pub fn iter_argument<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIterArgument, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let iter_expression =
+            CombinatorArg::Rule(("iter_expression", Parser::iter_expression as _, &block_args));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("var")),
+            opt!(f!(newlines)),
+            f!(lhs_pattern),
+            opt!(f!(newlines)),
+            s!("in"),
+            opt!(f!(newlines)),
+            iter_expression
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the binding is mutable.
+            let mutable = parser_result_values.remove(0) != Output::Empty;
+
+            // Pull Pat::* out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(1), Output::AST),
+                AST::Pat
+            );
+
+            // Pull SimpleExpr::* out of the seventh Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(4), Output::AST),
+                AST::SimpleExpr
+            );
+
+            result = Ok(Output::AST(AST::Iter(Iter {
+                args: vec![IterArg { mutable, pat, expr }],
+                guard: None,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses iterarguments =
+    ///     | iterargument (newlines? 'and' newlines? iterargument)* (newlines? 'where' newlines? simpleexpression)?
+    /// The guard of a condition is an infixexpression without object literals.
+    pub fn iter_arguments<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIterArguments, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let iter_argument =
+            || CombinatorArg::Rule(("iter_argument", Parser::iter_argument as _, &block_args));
+        let guard = match Parser::is_before_block(args) {
+            true => CombinatorArg::Rule((
+                "infix_expression",
+                Parser::infix_expression as _,
+                &block_args,
+            )),
+            false => {
+                CombinatorArg::Rule(("simple_expression", Parser::simple_expression as _, &[]))
+            }
+        };
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            iter_argument(),
+            optmore!(
+                opt!(f!(newlines)),
+                s!("and"),
+                opt!(f!(newlines)),
+                iter_argument()
+            ),
+            opt!(opt!(f!(newlines)), s!("where"), opt!(f!(newlines)), guard)
+        );
+
+        // Holds iteration arguments.
+        let mut args = vec![];
+
+        // Pulls the arguments out of an iter argument's AST::Iter.
+        let get_args = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::Iter).args
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            args.extend(get_args(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the fourth Output::AST.
+                    args.extend(get_args(values.remove(3)));
+                }
+            }
+
+            // Get the guard if it exists.
+            let output = parser_result_values.remove(0);
+            let guard = match output {
+                Output::Empty => None,
+                _ => {
+                    // Pull SimpleExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::SimpleExpr))
+                }
+            };
+
+            result = Ok(Output::AST(AST::Iter(Iter { args, guard })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses generatorcomprehension =
+    ///     | '(' newlines? comprehensionexpression newlines? '|' newlines? iterarguments newlines? ')'
+    pub fn generator_comprehension<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedGeneratorComprehension,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("("),
+            opt!(f!(newlines)),
+            f!(comprehension_expression),
+            opt!(f!(newlines)),
+            s!("|"),
+            opt!(f!(newlines)),
+            f!(iter_arguments),
+            opt!(f!(newlines)),
+            s!(")")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* and Iter out of the third and seventh Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::SimpleExpr
+            );
+            let iter = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Iter
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Compr(Box::new(
+                Compr {
+                    kind: ComprKind::Generator,
+                    expr,
+                    value: None,
+                    iter,
+                },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses listcomprehension =
+    ///     | '[' newlines? comprehensionexpression newlines? '|' newlines? iterarguments newlines? ']'
+    pub fn list_comprehension<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedListComprehension, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("["),
+            opt!(f!(newlines)),
+            f!(comprehension_expression),
+            opt!(f!(newlines)),
+            s!("|"),
+            opt!(f!(newlines)),
+            f!(iter_arguments),
+            opt!(f!(newlines)),
+            s!("]")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* and Iter out of the third and seventh Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::SimpleExpr
+            );
+            let iter = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Iter
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Compr(Box::new(
+                Compr {
+                    kind: ComprKind::List,
+                    expr,
+                    value: None,
+                    iter,
+                },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses dictcomprehension =
+    ///     | '{' newlines? comprehensionexpression newlines? ':' newlines? comprehensionexpression newlines? '|' newlines? iterarguments newlines? '}'
+    pub fn dict_comprehension<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedDictComprehension, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(comprehension_expression),
+            opt!(f!(newlines)),
+            s!(":"),
+            opt!(f!(newlines)),
+            f!(comprehension_expression),
+            opt!(f!(newlines)),
+            s!("|"),
+            opt!(f!(newlines)),
+            f!(iter_arguments),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* and Iter out of the third, seventh and eleventh Output::AST.
+            let key = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::SimpleExpr
+            );
+            let value = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::SimpleExpr
+            );
+            let iter = variant_value!(
+                variant_value!(parser_result_values.remove(8), Output::AST),
+                AST::Iter
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Compr(Box::new(
+                Compr {
+                    kind: ComprKind::Dict,
+                    expr: key,
+                    value: Some(value),
+                    iter,
+                },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses comprehension =
+    ///     | generatorcomprehension
+    ///     | listcomprehension
+    ///     | dictcomprehension
+    pub fn comprehension<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedComprehension, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(generator_comprehension),
+            f!(list_comprehension),
+            f!(dict_comprehension)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses callargument =
+    ///     | identifier newlines? ':' newlines? simpleexpression
+    ///     | simpleexpression
+    pub fn call_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedCallArgument, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                f!(identifier),
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(simple_expression)
+            ),
+            f!(simple_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let arg = match index {
+                // Named argument.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Get the name and pull SimpleExpr::* out of the fifth Output::AST.
+                    let name = Parser::get_terminal_value(values.remove(0));
+                    let expr = variant_value!(
+                        variant_value!(values.remove(3), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    CallArg {
+                        name: Some(name),
+                        expr,
+                    }
+                }
+                // Positional argument.
+                _ => {
+                    // Pull SimpleExpr::* out of Output::AST.
+                    let expr = variant_value!(variant_value!(value, Output::AST), AST::SimpleExpr);
+
+                    CallArg { name: None, expr }
+                }
+            };
+
+            // The called expression is filled in by atom.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Call {
+                expr: Box::new(SimpleExpr::Empty),
+                vectorized: false,
+                type_args: vec![],
+                args: vec![arg],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses callarguments =
+    ///     | callargument (comma callargument)* comma?
+    pub fn call_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedCallArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(call_argument),
+            optmore!(f!(comma), f!(call_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds arguments.
+        let mut args = vec![];
+
+        // Pulls the arguments out of a call argument's AST::SimpleExpr.
+        let get_args = |output: Output<AST>| {
+            let (_, _, _, args) = variant_fields!(
+                variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr),
+                SimpleExpr::Call,
+                { expr, vectorized, type_args, args }
+            );
+            args
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            args.extend(get_args(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the second Output::AST.
+                    args.extend(get_args(values.remove(1)));
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Call {
+                expr: Box::new(SimpleExpr::Empty),
+                vectorized: false,
+                type_args: vec![],
+                args,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses callpostfix =
+    ///     | '.'? ('[' newlines? typefunctionarguments newlines? ']')? '(' newlines? callarguments? newlines? ')'
+    pub fn call_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedCallPostfix, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!(".")),
+            opt!(
+                s!("["),
+                opt!(f!(newlines)),
+                f!(type_function_arguments),
+                opt!(f!(newlines)),
+                s!("]")
+            ),
+            s!("("),
+            opt!(f!(newlines)),
+            opt!(f!(call_arguments)),
+            opt!(f!(newlines)),
+            s!(")")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the call is vectorized.
+            let vectorized = parser_result_values.remove(0) != Output::Empty;
+
+            // Get the type arguments if they exist.
+            let value = parser_result_values.remove(0);
+            let type_args = match value {
+                Output::Empty => vec![],
+                _ => {
+                    // Pull TypeExpr::Tuple out of the third Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(value, Output::Values).remove(2),
+                        Output::AST
+                    );
+                    variant_value!(variant_value!(ast, AST::TypeExpr), TypeExpr::Tuple)
+                }
+            };
+
+            // Get the arguments if they exist.
+            let value = parser_result_values.remove(2);
+            let args = match value {
+                Output::Empty => vec![],
+                _ => {
+                    // Pull SimpleExpr::Call out of Output::Values.
+                    let ast = variant_value!(
+                        variant_value!(value, Output::Values).remove(0),
+                        Output::AST
+                    );
+                    let (_, _, _, args) = variant_fields!(
+                        variant_value!(ast, AST::SimpleExpr),
+                        SimpleExpr::Call,
+                        { expr, vectorized, type_args, args }
+                    );
+                    args
+                }
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Call {
+                expr: Box::new(SimpleExpr::Empty),
+                vectorized,
+                type_args,
+                args,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses dotnotationpostfix =
+    ///     | dot identifier
+    pub fn dot_notation_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedDotNotationPostfix,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, f!(dot), f!(identifier));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the name out of the second Output::AST.
+            let name = Parser::get_terminal_value(parser_result_values.remove(1));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Dot {
+                expr: Box::new(SimpleExpr::Empty),
+                name,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses indexarguments =
+    ///     | simpleexpression (comma simpleexpression)* comma?
+    pub fn index_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIndexArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(simple_expression),
+            optmore!(f!(comma), f!(simple_expression)),
+            opt!(f!(comma))
+        );
+
+        // Holds arguments.
+        let mut args = vec![];
+
+        // Pulls SimpleExpr::* out of Output::AST.
+        let get_arg = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            args.push(get_arg(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the second Output::AST.
+                    args.push(get_arg(values.remove(1)));
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Index {
+                expr: Box::new(SimpleExpr::Empty),
+                args,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses indexpostfix =
+    ///     | '[' newlines? indexarguments newlines? ']'
+    pub fn index_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIndexPostfix, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("["),
+            opt!(f!(newlines)),
+            f!(index_arguments),
+            opt!(f!(newlines)),
+            s!("]")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            result = Ok(parser_result_values.remove(2));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses tupleindexpostfix =
+    ///     | dot integerdecimalliteral
+    pub fn tuple_index_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTupleIndexPostfix, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, f!(dot), f!(integer_decimal_literal));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the index out of the second Output::AST.
+            let index = Parser::get_terminal_value(parser_result_values.remove(1));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::TupleIndex {
+                expr: Box::new(SimpleExpr::Empty),
+                index,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses postfix =
+    ///     | callpostfix
+    ///     | dotnotationpostfix
+    ///     | indexpostfix
+    ///     | tupleindexpostfix
+    ///     | '!'
+    pub fn postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedPostfix, span));
+
+        // Get parser result.
+        let parser_result = combinator.expect_as("postfix", |combinator| {
+            alt!(
+                combinator,
+                f!(call_postfix),
+                f!(dot_notation_postfix),
+                f!(index_postfix),
+                f!(tuple_index_postfix),
+                s!("!")
+            )
+        });
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = match index {
+                // The unwrapped expression is filled in by atom.
+                4 => Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Unwrap(Box::new(
+                    SimpleExpr::Empty,
+                ))))),
+                _ => Ok(array.remove(0)),
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses id =
+    ///     | identifier
+    ///     | placeholder
+    pub fn id<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedId, span));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, f!(identifier), f!(no_name));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses patternid =
+    ///     | '...'? id
+    ///     | '...'
+    pub fn pattern_id<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedPatternId, span));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, parse!(opt!(s!("...")), f!(id)), s!("..."));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            let (rest, name) = match index {
+                // Name with optional rest.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(array.remove(0), Output::Values);
+
+                    let rest = values.remove(0) != Output::Empty;
+                    (rest, Some(Parser::get_terminal_value(values.remove(0))))
+                }
+                // Unnamed rest.
+                _ => (true, None),
+            };
+
+            // The pattern argument is merged by lhspatternarguments.
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::Tuple,
+                args: vec![PatArg {
+                    mutable: false,
+                    rest,
+                    name,
+                    pat: None,
+                }],
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// TODO: Add emptytuple and operator.
+    /// Parses subatom =
+    ///     | conditionalexpression
+    ///     | comprehension
+    ///     | literal
+    ///     | id
+    ///     | '(' newlines? simpleexpression newlines? ')'
+    /// Comprehensions come first because containers also accept `|` as an infix operator.
+    pub fn sub_atom<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedSubAtom, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let literal = CombinatorArg::Rule(("literal", Parser::literal as _, &block_args));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(conditional_expression),
+            f!(comprehension),
+            literal,
+            f!(id),
+            parse!(
+                s!("("),
+                opt!(f!(newlines)),
+                f!(simple_expression),
+                opt!(f!(newlines)),
+                s!(")")
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            result = match index {
+                // Pull the parenthesized expression out of Output::Values.
+                4 => Ok(variant_value!(value, Output::Values).remove(2)),
+                _ => Ok(value),
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses keywordoperator =
+    ///     | 'is' 'not'
+    ///     | 'not' 'in'
+    ///     | 'in'
+    ///     | 'mod'
+    ///     | 'is'
+    ///     | 'not'
+    ///     | 'and'
+    ///     | 'or'
+    pub fn keyword_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedKeywordOperator, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(s!("is"), s!("not")),
+            parse!(s!("not"), s!("in")),
+            s!("in"),
+            s!("mod"),
+            s!("is"),
+            s!("not"),
+            s!("and"),
+            s!("or")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let operator = match index {
+                // Join the two keywords.
+                0 | 1 => variant_value!(value, Output::Values)
+                    .into_iter()
+                    .map(|output| variant_value!(output, Output::Str))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                // Pull the keyword out of Output::Str.
+                _ => variant_value!(value, Output::Str),
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Terminal {
+                kind: TokenKind::Keyword,
+                value: operator,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses infixoperator =
+    ///     | keywordoperator
+    ///     | operator
+    /// Operators reserved for other syntax, like `=`, and the prefix `not` are not infix operators.
+    pub fn infix_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedInfixOperator, span));
+
+        // Get parser result.
+        let parser_result = combinator.expect_as("operator", |combinator| {
+            alt!(combinator, f!(keyword_operator), f!(operator))
+        });
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            // Check if operator can be used as an infix operator.
+            if operators::is_infix_operator(&Parser::get_terminal_value(value.clone())) {
+                result = Ok(value);
+            } else {
+                // Revert advancement.
+                combinator.set_cursor(cursor);
+            }
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses prefixoperator =
+    ///     | operator
+    /// Operators reserved for other syntax, like `=`, are not prefix operators.
+    pub fn prefix_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedPrefixOperator, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, f!(operator));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(parser_result.unwrap(), Output::Values);
+            let value = array.remove(0);
+
+            // Check if operator can be used as a prefix operator.
+            if !operators::is_reserved_operator(&Parser::get_terminal_value(value.clone())) {
+                result = Ok(value);
+            } else {
+                // Revert advancement.
+                combinator.set_cursor(cursor);
+            }
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses postfixoperator =
+    ///     | operator
+    /// Operators reserved for other syntax, like `=`, and operators in the precedence table
+    /// are not postfix operators.
+    pub fn postfix_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedPostfixOperator, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, f!(operator));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(parser_result.unwrap(), Output::Values);
+            let value = array.remove(0);
+
+            // Check if operator can be used as a postfix operator.
+            if operators::is_postfix_operator(&Parser::get_terminal_value(value.clone())) {
+                result = Ok(value);
+            } else {
+                // Revert advancement.
+                combinator.set_cursor(cursor);
+            }
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses atom =
+    ///     | subatom postfix*
+    pub fn atom<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedAtom, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let sub_atom = CombinatorArg::Rule(("sub_atom", Parser::sub_atom as _, &block_args));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, sub_atom, optmore!(f!(postfix)));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* out of Output::AST.
+            let mut expr = variant_value!(
+                variant_value!(parser_result_values.remove(0), Output::AST),
+                AST::SimpleExpr
+            );
+
+            // Apply postfixes in order.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull SimpleExpr::* out of the first Output::AST.
+                    let postfix = variant_value!(
+                        variant_value!(
+                            variant_value!(values_enum, Output::Values).remove(0),
+                            Output::AST
+                        ),
+                        AST::SimpleExpr
+                    );
+                    expr = Parser::apply_postfix(expr, postfix);
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(expr)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses coefficientexpression =
+    ///     | coefficient NOSPACE identifier
+    ///     | coefficient NOSPACE '(' newlines? simpleexpression newlines? ')'
+    /// where coefficient =
+    ///     | floatbinaryliteral
+    ///     | floatoctalliteral
+    ///     | floatdecimalliteral
+    ///     | integerbinaryliteral
+    ///     | integeroctalliteral
+    ///     | integerdecimalliteral
+    ///     | '(' newlines? numericliteral newlines? ')'
+    /// Hexadecimal literals need parentheses to be coefficients since `0x344f` is a single literal.
+    pub fn coefficient_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedCoefficientExpression,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            alt!(
+                f!(float_binary_literal),
+                f!(float_octal_literal),
+                f!(float_decimal_literal),
+                f!(integer_binary_literal),
+                f!(integer_octal_literal),
+                f!(integer_decimal_literal),
+                parse!(
+                    s!("("),
+                    opt!(f!(newlines)),
+                    f!(numeric_literal),
+                    opt!(f!(newlines)),
+                    s!(")")
+                )
+            ),
+            f!(no_space),
+            alt!(
+                f!(identifier),
+                parse!(
+                    s!("("),
+                    opt!(f!(newlines)),
+                    f!(simple_expression),
+                    opt!(f!(newlines)),
+                    s!(")")
+                )
+            )
+        );
+
+        // Pulls SimpleExpr::* out of an alternative that is either a terminal or parenthesized.
+        let get_expr = |output: Output<AST>| {
+            let (value, _) = variant_fields!(output, Output::Alt, { value, index });
+            let ast = match variant_value!(*value, Output::Values).remove(0) {
+                // Pull the parenthesized expression out of Output::Values.
+                Output::Values(mut values) => values.remove(2),
+                value => value,
+            };
+            variant_value!(variant_value!(ast, Output::AST), AST::SimpleExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the coefficient and the expression out of the first and third Output::Alt.
+            let coefficient = get_expr(parser_result_values.remove(0));
+            let expr = get_expr(parser_result_values.remove(1));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Coeff {
+                coefficient: Box::new(coefficient),
+                expr: Box::new(expr),
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses prepostfixatom =
+    ///     | coefficientexpression
+    ///     | atom postfixoperator !atom
+    ///     | prefixoperator atom
+    ///     | atom
+    pub fn pre_post_fix_atom<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedPrePostfixAtom, span));
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let atom = || CombinatorArg::Rule(("atom", Parser::atom as _, &block_args));
+
+        // Hexadecimal literals can't be coefficients since `0x7fa` is a single literal,
+        // so `0x7f(a)` is neither a coefficient expression nor a call.
+        let is_hexadecimal_coefficient = not!(
+            combinator,
+            f!(integer_hexadecimal_literal),
+            f!(no_space),
+            alt!(s!("("), f!(identifier))
+        )
+        .is_err();
+
+        if is_hexadecimal_coefficient {
+            return result;
+        }
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(coefficient_expression),
+            parse!(atom(), f!(postfix_operator), not!(atom())),
+            parse!(f!(prefix_operator), atom()),
+            atom()
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            result = match index {
+                // Postfix operation.
+                1 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Pull SimpleExpr::* out of the first Output::AST.
+                    let expr = variant_value!(
+                        variant_value!(values.remove(0), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    Ok(Output::AST(AST::SimpleExpr(SimpleExpr::UnaryOp {
+                        op: Parser::get_terminal_value(values.remove(0)),
+                        fixity: Fixity::Postfix,
+                        expr: Box::new(expr),
+                    })))
+                }
+                // Prefix operation.
+                2 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Pull SimpleExpr::* out of the second Output::AST.
+                    let op = Parser::get_terminal_value(values.remove(0));
+                    let expr = variant_value!(
+                        variant_value!(values.remove(0), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    Ok(Output::AST(AST::SimpleExpr(SimpleExpr::UnaryOp {
+                        op,
+                        fixity: Fixity::Prefix,
+                        expr: Box::new(expr),
+                    })))
+                }
+                _ => Ok(value),
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses infixexpression =
+    ///     | 'not'* prepostfixatom (infixoperator 'not'* prepostfixatom)*
+    /// Operations are nested according to the precedence and associativity of their operators.
+    /// Operators passed as string arguments are not parsed as infix operators.
+    /// A `before_block` flag argument leaves out object literals.
+    pub fn infix_expression<'a>(
+        args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedInfixExpression, span));
+
+        // Get the operators that are not infix operators here.
+        let excluded_operators: Vec<CombinatorArg<AST>> = args
+            .iter()
+            .filter_map(|arg| match arg {
+                CombinatorArg::Str(operator) => Some(CombinatorArg::Str(operator)),
+                _ => None,
+            })
+            .collect();
+
+        // Object literals are left out before a block.
+        let block_args = Parser::get_before_block_args(args);
+        let operand = || {
+            CombinatorArg::Rule((
+                "pre_post_fix_atom",
+                Parser::pre_post_fix_atom as _,
+                &block_args,
+            ))
+        };
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            optmore!(s!("not")),
+            operand(),
+            optmore!(
+                not!(CombinatorArg::Func((
+                    "alt",
+                    Combinator::alt as _,
+                    &excluded_operators
+                ))),
+                f!(infix_operator),
+                optmore!(s!("not")),
+                operand()
+            )
+        );
+
+        // Holds the operands and operators in order.
+        let mut items = vec![];
+
+        // Adds a prefix `not` for each `not` in the output.
+        let push_nots = |items: &mut Vec<InfixItem>, output: Output<AST>| {
+            if output != Output::Empty {
+                for _ in variant_value!(output, Output::Values) {
+                    items.push(InfixItem::Not);
+                }
+            }
+        };
+
+        // Pulls SimpleExpr::* out of Output::AST.
+        let get_operand = |output: Output<AST>| {
+            InfixItem::Operand(variant_value!(
+                variant_value!(output, Output::AST),
+                AST::SimpleExpr
+            ))
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first operand.
+            push_nots(&mut items, parser_result_values.remove(0));
+            items.push(get_operand(parser_result_values.remove(0)));
+
+            // Get subsequent operators and operands.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the operator after the excluded operators check.
+                    items.push(InfixItem::Operator(Parser::get_terminal_value(
+                        values.remove(1),
+                    )));
+                    values.remove(0);
+                    push_nots(&mut items, values.remove(0));
+                    items.push(get_operand(values.remove(0)));
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(
+                operators::fold_infix_expression(items),
+            )));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses ternaryoperator =
+    ///     | '(' newlines? simpleexpression newlines? ')' newlines? '?' newlines? simpleexpression ':' newlines? simpleexpression
+    pub fn ternary_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTernaryOperator, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("("),
+            opt!(f!(newlines)),
+            f!(simple_expression),
+            opt!(f!(newlines)),
+            s!(")"),
+            opt!(f!(newlines)),
+            s!("?"),
+            opt!(f!(newlines)),
+            f!(simple_expression),
+            s!(":"),
+            opt!(f!(newlines)),
+            f!(simple_expression)
+        );
+
+        // Pulls SimpleExpr::* out of Output::AST.
+        let get_expr = |output: Output<AST>| {
+            Box::new(variant_value!(
+                variant_value!(output, Output::AST),
+                AST::SimpleExpr
+            ))
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the condition, consequent and alternative.
+            let condition = get_expr(parser_result_values.remove(2));
+            let consequent = get_expr(parser_result_values.remove(7));
+            let alternative = get_expr(parser_result_values.remove(9));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::TernaryOp {
+                condition,
+                consequent,
+                alternative,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses commandnotation =
+    ///     | identifier HASSPACE (comprehension | closure | ternaryoperator | range | identifier | symbolliteral | stringliteral | charliteral | regexliteral | numericliteral)
+    pub fn command_notation<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedCommandNotation, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(identifier),
+            f!(has_space),
+            alt!(
+                f!(comprehension),
+                f!(closure),
+                f!(ternary_operator),
+                f!(range),
+                f!(identifier),
+                f!(symbol_literal),
+                f!(string_literal),
+                f!(char_literal),
+                f!(regex_literal),
+                f!(numeric_literal)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* out of the first Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(0), Output::AST),
+                AST::SimpleExpr
+            );
+
+            // Pull the argument out of the third Output::Alt.
+            let (value, _) =
+                variant_fields!(parser_result_values.remove(1), Output::Alt, { value, index });
+            let arg = variant_value!(
+                variant_value!(
+                    variant_value!(*value, Output::Values).remove(0),
+                    Output::AST
+                ),
+                AST::SimpleExpr
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Call {
+                expr: Box::new(expr),
+                vectorized: false,
+                type_args: vec![],
+                args: vec![CallArg {
+                    name: None,
+                    expr: arg,
+                }],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses returnexpression =
+    ///     | 'return' simpleexpression?
+    pub fn return_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedReturnExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, s!("return"), opt!(f!(simple_expression)));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let expr = Parser::get_optional_expr(parser_result_values.remove(1));
+
+            result = Ok(Output::AST(AST::Expr(Expr::Return(expr))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses yieldexpression =
+    ///     | 'yield' 'from' newlines? simpleexpression
+    ///     | 'yield' simpleexpression?
+    pub fn yield_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedYieldExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                s!("yield"),
+                s!("from"),
+                opt!(f!(newlines)),
+                f!(simple_expression)
+            ),
+            parse!(s!("yield"), opt!(f!(simple_expression)))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            result = match index {
+                // Yield from.
+                0 => {
+                    // Pull SimpleExpr::* out of the fourth Output::AST.
+                    let expr = variant_value!(
+                        variant_value!(values.remove(3), Output::AST),
+                        AST::SimpleExpr
+                    );
+
+                    Ok(Output::AST(AST::Expr(Expr::Yield(true, Some(expr)))))
+                }
+                _ => {
+                    let expr = Parser::get_optional_expr(values.remove(1));
+
+                    Ok(Output::AST(AST::Expr(Expr::Yield(false, expr))))
+                }
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses continueexpression =
+    ///     | 'continue'
+    pub fn continue_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedContinueExpression,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, s!("continue"));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            result = Ok(Output::AST(AST::Expr(Expr::Continue)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses breakexpression =
+    ///     | 'break' simpleexpression?
+    pub fn break_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedBreakExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, s!("break"), opt!(f!(simple_expression)));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let expr = Parser::get_optional_expr(parser_result_values.remove(1));
+
+            result = Ok(Output::AST(AST::Expr(Expr::Break(expr))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses fallthroughexpression =
+    ///     | 'fallthrough'
+    pub fn fallthrough_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedFallthroughExpression,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, s!("fallthrough"));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            result = Ok(Output::AST(AST::Expr(Expr::Fallthrough)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses controlflowexpression =
+    ///     | returnexpression
+    ///     | yieldexpression
+    ///     | continueexpression
+    ///     | breakexpression
+    ///     | fallthroughexpression
+    /// Optional values have to start on the same line as their keyword.
+    pub fn control_flow_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedControlFlowExpression,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(return_expression),
+            f!(yield_expression),
+            f!(continue_expression),
+            f!(break_expression),
+            f!(fallthrough_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses fixexpression =
+    ///     | function
+    ///     | closure
+    ///     | ternaryoperator
+    ///     | commandnotation
+    ///     | infixexpression
+    pub fn fix_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedFixExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(function),
+            f!(closure),
+            f!(ternary_operator),
+            f!(command_notation),
+            f!(infix_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses spread =
+    ///     | '...' newlines? fixexpression
+    pub fn spread<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedSpread, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("..."),
+            opt!(f!(newlines)),
+            f!(fix_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* out of the third Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::SimpleExpr
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Spread(Box::new(
+                expr,
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses range =
+    ///     | fixexpression? '..' (fixexpression? '..')? fixexpression?
+    pub fn range<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedRange, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(f!(fix_expression)),
+            s!(".."),
+            opt!(opt!(f!(fix_expression)), s!("..")),
+            opt!(f!(fix_expression))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            result = Ok(Output::AST(AST::SimpleExpr(Parser::get_range(
+                parser_result_values,
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses simpleexpression =
+    ///     | spread
+    ///     | range
+    ///     | fixexpression
+    pub fn simple_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedSimpleExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, f!(spread), f!(range), f!(fix_expression));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses expression =
+    ///     | assignment
+    ///     | controlflowexpression
+    ///     | simpleexpression
+    pub fn expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(assignment),
+            f!(control_flow_expression),
+            f!(simple_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// TODO: Add block and importstatement.
+    /// Parses blockexpression =
+    ///     | expression
+    ///     | subject
+    ///     | type
+    ///     | enum
+    pub fn block_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedBlockExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(expression),
+            f!(subject),
+            f!(type_declaration),
+            f!(enum_declaration)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses expressions =
+    ///     | blockexpression ((';'? newlines | ';') blockexpression)* ';'?
+    pub fn expressions<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedExpressions, span));
+
+        // Recover from errors in the statements of the block.
+        if combinator.is_recovering() {
+            let expressions = Parser::parse_statements(combinator, true);
+
+            if expressions.is_empty() {
+                // Revert advancement.
+                combinator.set_cursor(cursor);
+                return result;
+            }
+
+            return Ok(Output::AST(AST::Block(expressions)));
+        }
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(block_expression),
+            optmore!(
+                alt!(parse!(opt!(s!(";")), f!(newlines)), s!(";")),
+                f!(block_expression)
+            ),
+            opt!(s!(";"))
+        );
+
+        // Holds expressions.
+        let mut expressions = vec![];
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull the first AST out of Output::AST.
+            expressions.push(variant_value!(parser_result_values.remove(0), Output::AST));
+
+            // Get subsequent expressions.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Pull AST out of the second Output::AST.
+                    expressions.push(variant_value!(values.remove(1), Output::AST));
+                }
+            }
+
+            result = Ok(Output::AST(AST::Block(expressions)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses program =
+    ///     | newlines? expressions? newlines?
+    pub fn program<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedProgram, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(f!(newlines)),
+            opt!(f!(expressions)),
+            opt!(f!(newlines))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the second element.
+            let value = parser_result_values.remove(1);
+
+            let expressions = match value {
+                // An empty program if there are no expressions.
+                Output::Empty => vec![],
+                // Otherwise pull AST::Block out of Output::Values.
+                _ => {
+                    let ast = variant_value!(
+                        variant_value!(value, Output::Values).remove(0),
+                        Output::AST
+                    );
+                    variant_value!(ast, AST::Block)
+                }
+            };
+
+            result = Ok(Output::AST(AST::Program(expressions)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// TODO: Add '`'.
+    /// Parses typesubatom =
+    ///     | identifier
+    pub fn type_sub_atom<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeSubAtom, span));
+
+        // Get parser result.
+        let parser_result = parse!(combinator, f!(identifier));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let name = Parser::get_terminal_value(array.remove(0));
+
+            result = Ok(Output::AST(AST::TypeExpr(TypeExpr::Name(name))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typepostfix =
+    ///     | '.' typesubatom
+    ///     | '[' newlines? typefunctionarguments newlines? ']'
+    pub fn type_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypePostfix, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(s!("."), f!(type_sub_atom)),
+            parse!(
+                s!("["),
+                opt!(f!(newlines)),
+                f!(type_function_arguments),
+                opt!(f!(newlines)),
+                s!("]")
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            // The type is filled in by typeatom.
+            let type_expr = Box::new(TypeExpr::Tuple(vec![]));
+
+            let postfix = match index {
+                // Pull TypeExpr::Name out of the second Output::AST.
+                0 => {
+                    let ast = variant_value!(values.remove(1), Output::AST);
+                    let name = variant_value!(variant_value!(ast, AST::TypeExpr), TypeExpr::Name);
+                    TypeExpr::Dot(type_expr, name)
+                }
+                // Pull TypeExpr::Tuple out of the third Output::AST.
+                _ => {
+                    let ast = variant_value!(values.remove(2), Output::AST);
+                    let args = variant_value!(variant_value!(ast, AST::TypeExpr), TypeExpr::Tuple);
+                    TypeExpr::Generic(type_expr, args)
+                }
+            };
+
+            result = Ok(Output::AST(AST::TypeExpr(postfix)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typetuple =
+    ///     | '(' newlines? typebinop newlines? ')'
+    ///     | '(' newlines? typefunctionarguments? newlines? ')'
+    /// A single type without a trailing comma is only parenthesized.
+    pub fn type_tuple<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeTuple, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                s!("("),
+                opt!(f!(newlines)),
+                f!(type_binop),
+                opt!(f!(newlines)),
+                s!(")")
+            ),
+            parse!(
+                s!("("),
+                opt!(f!(newlines)),
+                opt!(f!(type_function_arguments)),
+                opt!(f!(newlines)),
+                s!(")")
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            result = match index {
+                // Parenthesized type.
+                0 => Ok(values.remove(2)),
+                // Tuple type.
+                _ => match values.remove(2) {
+                    Output::Empty => Ok(Output::AST(AST::TypeExpr(TypeExpr::Tuple(vec![])))),
+                    output => Ok(variant_value!(output, Output::Values).remove(0)),
+                },
+            };
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typeatom =
+    ///     | typesubatom typepostfix* ('!' | '?')?
+    ///     | typetuple ('!' | '?')?
+    pub fn type_atom<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeAtom, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            alt!(
+                parse!(f!(type_sub_atom), optmore!(f!(type_postfix))),
+                f!(type_tuple)
+            ),
+            opt!(alt!(s!("!"), s!("?")))
+        );
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_type = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull fields out of the first Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result_values.remove(0), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let mut type_expr = match index {
+                // Type with postfixes.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let mut type_expr = get_type(values.remove(0));
+
+                    // Apply postfixes in order.
+                    let output = values.remove(0);
+                    if output != Output::Empty {
+                        for values_enum in variant_value!(output, Output::Values) {
+                            let postfix =
+                                get_type(variant_value!(values_enum, Output::Values).remove(0));
+                            type_expr = Parser::apply_type_postfix(type_expr, postfix);
+                        }
+                    }
+
+                    type_expr
+                }
+                // Tuple type.
+                _ => get_type(value),
+            };
+
+            // Apply the suffix if it exists.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull index field out of Output::Alt.
+                let (_, index) = variant_fields!(
+                    variant_value!(output, Output::Values).remove(0),
+                    Output::Alt,
+                    { value, index }
+                );
+
+                type_expr = match index {
+                    0 => TypeExpr::Errable(Box::new(type_expr)),
+                    _ => TypeExpr::Optional(Box::new(type_expr)),
+                };
+            }
+
+            result = Ok(Output::AST(AST::TypeExpr(type_expr)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typebinop =
+    ///     | typeatom '|' typebinop
+    ///     | typeatom '&' typebinop
+    ///     | typeatom
+    /// Successive operations of the same kind are flattened into one union or intersection.
+    pub fn type_binop<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeBinop, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(f!(type_atom), s!("|"), f!(type_binop)),
+            parse!(f!(type_atom), s!("&"), f!(type_binop)),
+            f!(type_atom)
+        );
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_type = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let type_expr = match index {
+                // Union or intersection.
+                0 | 1 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let lhs = get_type(values.remove(0));
+                    let rhs = get_type(values.remove(1));
+
+                    match (index, rhs) {
+                        (0, TypeExpr::Union(mut types)) => {
+                            types.insert(0, lhs);
+                            TypeExpr::Union(types)
+                        }
+                        (0, rhs) => TypeExpr::Union(vec![lhs, rhs]),
+                        (_, TypeExpr::Intersection(mut types)) => {
+                            types.insert(0, lhs);
+                            TypeExpr::Intersection(types)
+                        }
+                        (_, rhs) => TypeExpr::Intersection(vec![lhs, rhs]),
+                    }
+                }
+                _ => get_type(value),
+            };
+
+            result = Ok(Output::AST(AST::TypeExpr(type_expr)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typefunctionarguments =
+    ///     | typebinop (comma typebinop)* comma?
+    pub fn type_function_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeFunctionArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(type_binop),
+            optmore!(f!(comma), f!(type_binop)),
+            opt!(f!(comma))
+        );
+
+        // Holds type expressions.
+        let mut type_exprs = vec![];
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull TypeExpr::* out of the first Output::AST.
+            let ast = variant_value!(parser_result_values.remove(0), Output::AST);
+            type_exprs.push(variant_value!(ast, AST::TypeExpr));
+
+            // Get subsequent type expressions.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Pull TypeExpr::* out of the second Output::AST.
+                    let ast = variant_value!(values.remove(1), Output::AST);
+                    type_exprs.push(variant_value!(ast, AST::TypeExpr));
+                }
+            }
+
+            result = Ok(Output::AST(AST::TypeExpr(TypeExpr::Tuple(type_exprs))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typerelationoperator =
+    ///     | '::'
+    ///     | '>:'
+    ///     | '<:'
+    pub fn type_relation_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeRelationOperator,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, s!("::"), s!(">:"), s!("<:"));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull the operator out of Output::Str.
+            let operator = variant_value!(
+                variant_value!(*value, Output::Values).remove(0),
+                Output::Str
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Terminal {
+                kind: TokenKind::Operator,
+                value: operator,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typerelationargument =
+    ///     | '|' newlines? typeatom (comma typeatom)* comma? newlines? '|' newlines? typerelationoperator typeatom
+    ///     | typeatom typerelationoperator typeatom
+    pub fn type_relation_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeRelationArgument,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                s!("|"),
+                opt!(f!(newlines)),
+                f!(type_atom),
+                optmore!(f!(comma), f!(type_atom)),
+                opt!(f!(comma)),
+                opt!(f!(newlines)),
+                s!("|"),
+                opt!(f!(newlines)),
+                f!(type_relation_operator),
+                f!(type_atom)
+            ),
+            parse!(f!(type_atom), f!(type_relation_operator), f!(type_atom))
+        );
+
+        // Holds the types on the left of the operator.
+        let mut lhs = vec![];
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_type = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            let (op, rhs) = match index {
+                // Several types.
+                0 => {
+                    // Get the first type.
+                    lhs.push(get_type(values.remove(2)));
+
+                    // Get subsequent types.
+                    let output = values.remove(2);
+                    if output != Output::Empty {
+                        for values_enum in variant_value!(output, Output::Values) {
+                            // Pull TypeExpr::* out of the second Output::AST.
+                            lhs.push(get_type(
+                                variant_value!(values_enum, Output::Values).remove(1),
+                            ));
+                        }
+                    }
+
+                    let op = Parser::get_terminal_value(values.remove(6));
+                    (op, get_type(values.remove(6)))
+                }
+                // Single type.
+                _ => {
+                    lhs.push(get_type(values.remove(0)));
+
+                    let op = Parser::get_terminal_value(values.remove(0));
+                    (op, get_type(values.remove(0)))
+                }
+            };
+
+            // The relation is merged by typerelationarguments.
+            result = Ok(Output::AST(AST::TypeExpr(TypeExpr::Function {
+                params: vec![],
+                ret: Box::new(TypeExpr::Tuple(vec![])),
+                relations: vec![TypeRelation { lhs, op, rhs }],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typerelationarguments =
+    ///     | typerelationargument (comma typerelationargument)* comma?
+    pub fn type_relation_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeRelationArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(type_relation_argument),
+            optmore!(f!(comma), f!(type_relation_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds type relations.
+        let mut relations = vec![];
+
+        // Pulls TypeRelation out of a partial TypeExpr::Function.
+        let get_relation = |output: Output<AST>| {
+            let type_expr = variant_value!(variant_value!(output, Output::AST), AST::TypeExpr);
+            let (_, _, mut relations) = variant_fields!(type_expr, TypeExpr::Function, {
+                params,
+                ret,
+                relations
+            });
+            relations.remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first relation.
+            relations.push(get_relation(parser_result_values.remove(0)));
+
+            // Get subsequent relations.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the relation out of the second Output::AST.
+                    relations.push(get_relation(values.remove(1)));
+                }
+            }
+
+            // The relations are merged by typeexpression.
+            result = Ok(Output::AST(AST::TypeExpr(TypeExpr::Function {
+                params: vec![],
+                ret: Box::new(TypeExpr::Tuple(vec![])),
+                relations,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typeexpression =
+    ///     | '(' newlines? typefunctionarguments? newlines? ')' newlines? '->' newlines? typebinop (newlines? 'where' newlines? typerelationarguments)?
+    ///     | typebinop
+    pub fn type_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeExpression, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                s!("("),
+                opt!(f!(newlines)),
+                opt!(f!(type_function_arguments)),
+                opt!(f!(newlines)),
+                s!(")"),
+                opt!(f!(newlines)),
+                s!("->"),
+                opt!(f!(newlines)),
+                f!(type_binop),
+                opt!(
+                    opt!(f!(newlines)),
+                    s!("where"),
+                    opt!(f!(newlines)),
+                    f!(type_relation_arguments)
+                )
+            ),
+            f!(type_binop)
+        );
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_type = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let type_expr = match index {
+                // Function type.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Get the parameter types if they exist.
+                    let params = match values.remove(2) {
+                        Output::Empty => vec![],
+                        output => variant_value!(
+                            get_type(variant_value!(output, Output::Values).remove(0)),
+                            TypeExpr::Tuple
+                        ),
+                    };
+
+                    // Pull TypeExpr::* out of the ninth Output::AST.
+                    let ret = Box::new(get_type(values.remove(7)));
+
+                    // Get the relations if they exist.
+                    let relations = match values.remove(7) {
+                        Output::Empty => vec![],
+                        output => {
+                            // Pull the relations out of the fourth Output::AST.
+                            let type_expr =
+                                get_type(variant_value!(output, Output::Values).remove(3));
+                            let (_, _, relations) = variant_fields!(type_expr, TypeExpr::Function, {
+                                params,
+                                ret,
+                                relations
+                            });
+                            relations
+                        }
+                    };
+
+                    TypeExpr::Function {
+                        params,
+                        ret,
+                        relations,
+                    }
+                }
+                _ => get_type(value),
+            };
+
+            result = Ok(Output::AST(AST::TypeExpr(type_expr)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhsrefpostfix =
+    ///     | dotnotationpostfix
+    ///     | indexpostfix
+    ///     | tupleindexpostfix
+    pub fn lhs_ref_postfix<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsRefPostfix, span));
+
+        // Get parser result.
+        let parser_result = combinator.expect_as("postfix", |combinator| {
+            alt!(
+                combinator,
+                f!(dot_notation_postfix),
+                f!(index_postfix),
+                f!(tuple_index_postfix)
+            )
+        });
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhsref =
+    ///     | '*'? (id | '(' newlines? lhsref newlines? ')') lhsrefpostfix*
+    /// The dereference applies to the whole reference, so `*ptr.field` is `*(ptr.field)`.
+    pub fn lhs_ref<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsRef, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("*")),
+            alt!(
+                f!(id),
+                parse!(
+                    s!("("),
+                    opt!(f!(newlines)),
+                    f!(lhs_ref),
+                    opt!(f!(newlines)),
+                    s!(")")
+                )
+            ),
+            optmore!(f!(lhs_ref_postfix))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the reference is dereferenced.
+            let deref = parser_result_values.remove(0) != Output::Empty;
+
+            // Pull fields out of the second Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result_values.remove(0), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            let mut expr = match index {
+                // Identifier.
+                0 => variant_value!(
+                    variant_value!(array.remove(0), Output::AST),
+                    AST::SimpleExpr
+                ),
+                // Parenthesized reference.
+                _ => {
+                    // Pull Pat::Ref out of the third Output::AST.
+                    let mut values = variant_value!(array.remove(0), Output::Values);
+                    let pat =
+                        variant_value!(variant_value!(values.remove(2), Output::AST), AST::Pat);
+                    variant_value!(pat, Pat::Ref)
+                }
+            };
+
+            // Apply postfixes in order.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull SimpleExpr::* out of the first Output::AST.
+                    let postfix = variant_value!(
+                        variant_value!(
+                            variant_value!(values_enum, Output::Values).remove(0),
+                            Output::AST
+                        ),
+                        AST::SimpleExpr
+                    );
+                    expr = Parser::apply_postfix(expr, postfix);
+                }
+            }
+
+            if deref {
+                expr = SimpleExpr::UnaryOp {
+                    op: "*".into(),
+                    fixity: Fixity::Prefix,
+                    expr: Box::new(expr),
+                };
+            }
+
+            result = Ok(Output::AST(AST::Pat(Pat::Ref(expr))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhspatternargument =
+    ///     | ('var' newlines?)? id newlines? ':' newlines? lhspattern
+    ///     | ('var' newlines?)? patternid
+    pub fn lhs_pattern_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedLhsPatternArgument,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                opt!(s!("var"), opt!(f!(newlines))),
+                f!(id),
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(lhs_pattern)
+            ),
+            parse!(opt!(s!("var"), opt!(f!(newlines))), f!(pattern_id))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            // Check if the binding is mutable.
+            let mutable = values.remove(0) != Output::Empty;
+
+            let arg = match index {
+                // Named pattern.
+                0 => {
+                    let name = Parser::get_terminal_value(values.remove(0));
+
+                    // Pull Pat::* out of the fourth Output::AST.
+                    let pat =
+                        variant_value!(variant_value!(values.remove(3), Output::AST), AST::Pat);
+
+                    PatArg {
+                        mutable,
+                        rest: false,
+                        name: Some(name),
+                        pat: Some(pat),
+                    }
+                }
+                // Pattern identifier.
+                _ => {
+                    // Pull the argument out of the partial Pat::Container.
+                    let pat =
+                        variant_value!(variant_value!(values.remove(0), Output::AST), AST::Pat);
+                    let mut arg = variant_value!(pat, Pat::Container).args.remove(0);
+
+                    arg.mutable = mutable;
+                    arg
+                }
+            };
+
+            // The pattern argument is merged by lhspatternarguments.
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::Tuple,
+                args: vec![arg],
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhspatternarguments =
+    ///     | lhspatternargument (comma lhspatternargument)* comma?
+    pub fn lhs_pattern_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedLhsPatternArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(lhs_pattern_argument),
+            optmore!(f!(comma), f!(lhs_pattern_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds arguments.
+        let mut args = vec![];
+
+        // Pulls PatArg out of a partial Pat::Container.
+        let get_arg = |output: Output<AST>| {
+            let pat = variant_value!(variant_value!(output, Output::AST), AST::Pat);
+            variant_value!(pat, Pat::Container).args.remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            args.push(get_arg(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the second Output::AST.
+                    args.push(get_arg(values.remove(1)));
+                }
+            }
+
+            // The kind is set by the container pattern.
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::Tuple,
+                args,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhstuplepattern =
+    ///     | '(' newlines? lhspatternarguments newlines? ')'
+    pub fn lhs_tuple_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsTuplePattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("("),
+            opt!(f!(newlines)),
+            f!(lhs_pattern_arguments),
+            opt!(f!(newlines)),
+            s!(")")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Pat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Pat
+            );
+            let args = variant_value!(pat, Pat::Container).args;
+
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::Tuple,
+                args,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhsdictpattern =
+    ///     | '{' newlines? lhspatternarguments newlines? '}'
+    pub fn lhs_dict_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsDictPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(lhs_pattern_arguments),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Pat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Pat
+            );
+            let args = variant_value!(pat, Pat::Container).args;
+
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::Dict,
+                args,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhslistpattern =
+    ///     | '[' newlines? lhspatternarguments newlines? ']'
+    pub fn lhs_list_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsListPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("["),
+            opt!(f!(newlines)),
+            f!(lhs_pattern_arguments),
+            opt!(f!(newlines)),
+            s!("]")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Pat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Pat
+            );
+            let args = variant_value!(pat, Pat::Container).args;
+
+            result = Ok(Output::AST(AST::Pat(Pat::Container(ContainerPat {
+                kind: PatKind::List,
+                args,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhsobjectpattern =
+    ///     | typeatom (lhstuplepattern | lhsdictpattern)
+    pub fn lhs_object_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsObjectPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(type_atom),
+            alt!(f!(lhs_tuple_pattern), f!(lhs_dict_pattern))
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull TypeExpr::* out of the first Output::AST.
+            let type_expr = variant_value!(
+                variant_value!(parser_result_values.remove(0), Output::AST),
+                AST::TypeExpr
+            );
+
+            // Pull Pat::Container out of the second Output::Alt.
+            let (value, _) =
+                variant_fields!(parser_result_values.remove(0), Output::Alt, { value, index });
+            let pat = variant_value!(
+                variant_value!(
+                    variant_value!(*value, Output::Values).remove(0),
+                    Output::AST
+                ),
+                AST::Pat
+            );
+
+            result = Ok(Output::AST(AST::Pat(Pat::Object(
+                type_expr,
+                variant_value!(pat, Pat::Container),
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhscontainerpattern =
+    ///     | lhstuplepattern
+    ///     | lhsdictpattern
+    ///     | lhslistpattern
+    ///     | lhsobjectpattern
+    pub fn lhs_container_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedLhsContainerPattern,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(lhs_tuple_pattern),
+            f!(lhs_dict_pattern),
+            f!(lhs_list_pattern),
+            f!(lhs_object_pattern)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses lhspattern =
+    ///     | lhscontainerpattern
+    ///     | lhsref
+    pub fn lhs_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLhsPattern, span));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, f!(lhs_container_pattern), f!(lhs_ref));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchpatternargument =
+    ///     | ('var' newlines?)? id newlines? ':' newlines? matchpattern
+    ///     | ('var' newlines?)? '..' id
+    ///     | ('var' newlines?)? matchpattern
+    ///     | ('var' newlines?)? patternid
+    /// A lone id is a name, like in patternid, and not a pattern.
+    pub fn match_pattern_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedMatchPatternArgument,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                opt!(s!("var"), opt!(f!(newlines))),
+                f!(id),
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(match_pattern)
+            ),
+            parse!(opt!(s!("var"), opt!(f!(newlines))), s!(".."), f!(id)),
+            parse!(opt!(s!("var"), opt!(f!(newlines))), f!(match_pattern)),
+            parse!(opt!(s!("var"), opt!(f!(newlines))), f!(pattern_id))
+        );
+
+        // Pulls MatchPat::* out of Output::AST.
+        let get_pat = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::MatchPat)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let mut values = variant_value!(array.remove(0), Output::Values);
+
+            // Check if the binding is mutable.
+            let mutable = values.remove(0) != Output::Empty;
+
+            let (rest, name, pat) = match index {
+                // Named pattern.
+                0 => {
+                    let name = Parser::get_terminal_value(values.remove(0));
+                    (false, Some(name), Some(get_pat(values.remove(3))))
+                }
+                // Rest binding.
+                1 => (
+                    true,
+                    Some(Parser::get_terminal_value(values.remove(1))),
+                    None,
+                ),
+                // Pattern.
+                2 => match get_pat(values.remove(0)) {
+                    MatchPat::Id {
+                        name,
+                        relation: None,
+                    } => (false, Some(name), None),
+                    pat => (false, None, Some(pat)),
+                },
+                // Pattern identifier.
+                _ => {
+                    // Pull the argument out of the partial Pat::Container.
+                    let pat =
+                        variant_value!(variant_value!(values.remove(0), Output::AST), AST::Pat);
+                    let arg = variant_value!(pat, Pat::Container).args.remove(0);
+                    (arg.rest, arg.name, None)
+                }
+            };
+
+            // The pattern argument is merged by matchpatternarguments.
+            result = Ok(Output::AST(AST::MatchPat(MatchPat::Container(
+                MatchContainerPat {
+                    kind: PatKind::Tuple,
+                    args: vec![MatchPatArg {
+                        mutable,
+                        rest,
+                        name,
+                        pat,
+                    }],
+                },
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchpatternarguments =
+    ///     | matchpatternargument (comma matchpatternargument)* comma?
+    pub fn match_pattern_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedMatchPatternArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(match_pattern_argument),
+            optmore!(f!(comma), f!(match_pattern_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds arguments.
+        let mut args = vec![];
+
+        // Pulls MatchPatArg out of a partial MatchPat::Container.
+        let get_arg = |output: Output<AST>| {
+            let pat = variant_value!(variant_value!(output, Output::AST), AST::MatchPat);
+            variant_value!(pat, MatchPat::Container).args.remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            args.push(get_arg(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the second Output::AST.
+                    args.push(get_arg(values.remove(1)));
+                }
+            }
+
+            // The kind is set by the container pattern.
+            result = Ok(Output::AST(AST::MatchPat(MatchPat::Container(
+                MatchContainerPat {
+                    kind: PatKind::Tuple,
+                    args,
+                },
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchtuplepattern =
+    ///     | '(' newlines? matchpatternarguments newlines? ')'
+    pub fn match_tuple_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchTuplePattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("("),
+            opt!(f!(newlines)),
+            f!(match_pattern_arguments),
+            opt!(f!(newlines)),
+            s!(")")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull MatchPat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::MatchPat
+            );
+            let args = variant_value!(pat, MatchPat::Container).args;
+
+            result = Ok(Output::AST(AST::MatchPat(MatchPat::Container(
+                MatchContainerPat {
+                    kind: PatKind::Tuple,
+                    args,
+                },
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchdictpattern =
+    ///     | '{' newlines? matchpatternarguments newlines? '}'
+    pub fn match_dict_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchDictPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(match_pattern_arguments),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull MatchPat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::MatchPat
+            );
+            let args = variant_value!(pat, MatchPat::Container).args;
+
+            result = Ok(Output::AST(AST::MatchPat(MatchPat::Container(
+                MatchContainerPat {
+                    kind: PatKind::Dict,
+                    args,
+                },
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchlistpattern =
+    ///     | '[' newlines? matchpatternarguments newlines? ']'
+    pub fn match_list_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchListPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("["),
+            opt!(f!(newlines)),
+            f!(match_pattern_arguments),
+            opt!(f!(newlines)),
+            s!("]")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull MatchPat::Container out of the third Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::MatchPat
+            );
+            let args = variant_value!(pat, MatchPat::Container).args;
+
+            result = Ok(Output::AST(AST::MatchPat(MatchPat::Container(
+                MatchContainerPat {
+                    kind: PatKind::List,
+                    args,
+                },
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchcontainerpattern =
+    ///     | matchtuplepattern
+    ///     | matchdictpattern
+    ///     | matchlistpattern
+    pub fn match_container_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedMatchContainerPattern,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(match_tuple_pattern),
+            f!(match_dict_pattern),
+            f!(match_list_pattern)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchsubpattern =
+    ///     | typeatom (matchtuplepattern | matchdictpattern)
+    ///     | matchcontainerpattern
+    ///     | infixexpression '..' infixexpression
+    ///     | id (newlines? typerelationoperator newlines? typeatom)?
+    ///     | infixexpression
+    /// Infix expressions here leave out `|`, which separates alternative patterns.
+    pub fn match_sub_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchSubPattern, span));
+
+        // Infix expressions without `|`.
+        let excluded_operators = vec![s!("|")];
+        let operand = || {
+            CombinatorArg::Rule((
+                "match_infix_expression",
+                Parser::infix_expression as _,
+                &excluded_operators,
+            ))
+        };
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                f!(type_atom),
+                alt!(f!(match_tuple_pattern), f!(match_dict_pattern))
+            ),
+            f!(match_container_pattern),
+            parse!(operand(), s!(".."), operand()),
+            parse!(
+                f!(id),
+                opt!(
+                    opt!(f!(newlines)),
+                    f!(type_relation_operator),
+                    opt!(f!(newlines)),
+                    f!(type_atom)
+                )
+            ),
+            operand()
+        );
+
+        // Pulls SimpleExpr::* out of Output::AST.
+        let get_expr = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr)
+        };
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_type = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let pat = match index {
+                // Object pattern.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let type_expr = get_type(values.remove(0));
+
+                    // Pull MatchPat::Container out of the second Output::Alt.
+                    let (value, _) =
+                        variant_fields!(values.remove(0), Output::Alt, { value, index });
+                    let pat = variant_value!(
+                        variant_value!(
+                            variant_value!(*value, Output::Values).remove(0),
+                            Output::AST
+                        ),
+                        AST::MatchPat
+                    );
+
+                    MatchPat::Object(type_expr, variant_value!(pat, MatchPat::Container))
+                }
+                // Container pattern.
+                1 => variant_value!(variant_value!(value, Output::AST), AST::MatchPat),
+                // Range pattern.
+                2 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let start = get_expr(values.remove(0));
+                    let end = get_expr(values.remove(1));
+
+                    MatchPat::Range { start, end }
+                }
+                // Identifier with an optional type relation.
+                3 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let name = Parser::get_terminal_value(values.remove(0));
+
+                    let relation = match values.remove(0) {
+                        Output::Empty => None,
+                        output => {
+                            // Pull array out of Output::Values.
+                            let mut values = variant_value!(output, Output::Values);
+
+                            let operator = Parser::get_terminal_value(values.remove(1));
+                            Some((operator, get_type(values.remove(2))))
+                        }
+                    };
+
+                    MatchPat::Id { name, relation }
+                }
+                // Literal pattern.
+                _ => MatchPat::Literal(get_expr(value)),
+            };
+
+            result = Ok(Output::AST(AST::MatchPat(pat)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchpattern =
+    ///     | matchsubpattern (newlines? '|' newlines? matchsubpattern)*
+    pub fn match_pattern<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchPattern, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(match_sub_pattern),
+            optmore!(
+                opt!(f!(newlines)),
+                s!("|"),
+                opt!(f!(newlines)),
+                f!(match_sub_pattern)
+            )
+        );
+
+        // Holds alternative patterns.
+        let mut pats = vec![];
+
+        // Pulls MatchPat::* out of Output::AST.
+        let get_pat = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::MatchPat)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first pattern.
+            pats.push(get_pat(parser_result_values.remove(0)));
+
+            // Get subsequent patterns.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the pattern out of the fourth Output::AST.
+                    pats.push(get_pat(values.remove(3)));
+                }
+            }
+
+            // Alternatives are only needed for more than one pattern.
+            let pat = if pats.len() == 1 {
+                pats.remove(0)
+            } else {
+                MatchPat::Alt(pats)
+            };
+
+            result = Ok(Output::AST(AST::MatchPat(pat)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchargument =
+    ///     | matchpattern newlines? '=>' newlines? '{' newlines? expressions newlines? '}'
+    ///     | matchpattern newlines? '=>' newlines? expression
+    pub fn match_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchArgument, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(match_pattern),
+            opt!(f!(newlines)),
+            s!("=>"),
+            opt!(f!(newlines)),
+            alt!(
+                parse!(
+                    s!("{"),
+                    opt!(f!(newlines)),
+                    f!(expressions),
+                    opt!(f!(newlines)),
+                    s!("}")
+                ),
+                f!(expression)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull MatchPat::* out of the first Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(0), Output::AST),
+                AST::MatchPat
+            );
+
+            // Pull fields out of the fifth Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result_values.remove(3), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let body = match index {
+                // Pull AST::Block out of the third Output::AST.
+                0 => variant_value!(
+                    variant_value!(variant_value!(value, Output::Values).remove(2), Output::AST),
+                    AST::Block
+                ),
+                // Single expression.
+                _ => vec![variant_value!(value, Output::AST)],
+            };
+
+            // The matched expression is filled in by matchexpression.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Match(Box::new(
+                Match {
+                    expr: SimpleExpr::Empty,
+                    arms: vec![MatchArm { pat, body }],
+                },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matcharguments =
+    ///     | matchargument (comma matchargument)* comma?
+    pub fn match_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(match_argument),
+            optmore!(f!(comma), f!(match_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds match arms.
+        let mut arms = vec![];
+
+        // Pulls MatchArm out of a partial SimpleExpr::Match.
+        let get_arm = |output: Output<AST>| {
+            let expr = variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr);
+            variant_value!(expr, SimpleExpr::Match).arms.remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first arm.
+            arms.push(get_arm(parser_result_values.remove(0)));
+
+            // Get subsequent arms.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the arm out of the second Output::AST.
+                    arms.push(get_arm(values.remove(1)));
+                }
+            }
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Match(Box::new(
+                Match {
+                    expr: SimpleExpr::Empty,
+                    arms,
+                },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses matchexpression =
+    ///     | 'match' newlines? simpleexpression newlines? '{' newlines? matcharguments newlines? '}'
+    pub fn match_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedMatchExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("match"),
+            opt!(f!(newlines)),
+            f!(simple_expression),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(match_arguments),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull SimpleExpr::* out of the third Output::AST.
+            let expr = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::SimpleExpr
+            );
+
+            // Pull the arms out of the seventh Output::AST.
+            let arms = variant_value!(
+                variant_value!(
+                    variant_value!(parser_result_values.remove(5), Output::AST),
+                    AST::SimpleExpr
+                ),
+                SimpleExpr::Match
+            )
+            .arms;
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Match(Box::new(
+                Match { expr, arms },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses elifexpression =
+    ///     | 'elif' newlines? condition newlines? '{' newlines? expressions newlines? '}'
+    pub fn elif_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedElifExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("elif"),
+            opt!(f!(newlines)),
+            f!(condition),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Condition::* out of the third Output::AST.
+            let condition = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Condition
+            );
+
+            // Pull AST::Block out of the seventh Output::AST.
+            let body = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Block
+            );
+
+            // The elif is merged by ifexpression.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::If(Box::new(If {
+                condition,
+                body,
+                elifs: vec![],
+                else_body: None,
+            })))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses elseexpression =
+    ///     | 'else' newlines? '{' newlines? expressions newlines? '}'
+    pub fn else_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedElseExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("else"),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the AST::Block in the fifth Output::AST.
+            result = Ok(parser_result_values.remove(4));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses condition =
+    ///     | ('let' | 'var') newlines? lhspattern newlines? '=' newlines? infixexpression
+    ///     | infixexpression
+    /// The infix expressions leave out object literals because the `{` after a condition starts its block.
+    pub fn condition<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedCondition, span));
+
+        // Infix expressions without object literals.
+        let block_args = vec![CombinatorArg::Flag(Parser::BEFORE_BLOCK)];
+        let expr = || {
+            CombinatorArg::Rule((
+                "infix_expression",
+                Parser::infix_expression as _,
+                &block_args,
+            ))
+        };
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                alt!(s!("let"), s!("var")),
+                opt!(f!(newlines)),
+                f!(lhs_pattern),
+                opt!(f!(newlines)),
+                s!("="),
+                opt!(f!(newlines)),
+                expr()
+            ),
+            expr()
+        );
+
+        // Pulls SimpleExpr::* out of Output::AST.
+        let get_expr = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let condition = match index {
+                // Pattern binding.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Check if the binding is mutable.
+                    let (_, index) =
+                        variant_fields!(values.remove(0), Output::Alt, { value, index });
+                    let mutable = index == 1;
+
+                    // Pull Pat::* out of the third Output::AST.
+                    let pat =
+                        variant_value!(variant_value!(values.remove(1), Output::AST), AST::Pat);
+
+                    Condition::Binding {
+                        mutable,
+                        pat,
+                        expr: get_expr(values.remove(4)),
+                    }
+                }
+                // Expression.
+                _ => Condition::Expr(get_expr(value)),
+            };
+
+            result = Ok(Output::AST(AST::Condition(condition)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses ifexpression =
+    ///     | 'if' newlines? condition newlines? '{' newlines? expressions newlines? '}' (newlines? elifexpression)* (newlines? elseexpression)?
+    pub fn if_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedIfExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("if"),
+            opt!(f!(newlines)),
+            f!(condition),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}"),
+            optmore!(opt!(f!(newlines)), f!(elif_expression)),
+            opt!(opt!(f!(newlines)), f!(else_expression))
+        );
+
+        // Holds elif conditions and bodies.
+        let mut elifs = vec![];
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Condition::* out of the third Output::AST.
+            let condition = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Condition
+            );
+
+            // Pull AST::Block out of the seventh Output::AST.
+            let body = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Block
+            );
+
+            // Get the elifs.
+            let output = parser_result_values.remove(7);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull the partial If out of the second Output::AST.
+                    let expr = variant_value!(
+                        variant_value!(
+                            variant_value!(values_enum, Output::Values).remove(1),
+                            Output::AST
+                        ),
+                        AST::SimpleExpr
+                    );
+                    let elif = variant_value!(expr, SimpleExpr::If);
+
+                    elifs.push((elif.condition, elif.body));
+                }
+            }
+
+            // Get the else body if it exists.
+            let else_body = match parser_result_values.remove(7) {
+                Output::Empty => None,
+                output => {
+                    // Pull AST::Block out of the second Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(1),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::Block))
+                }
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::If(Box::new(If {
+                condition,
+                body,
+                elifs,
+                else_body,
+            })))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses loopexpression =
+    ///     | 'loop' newlines? '{' newlines? expressions newlines? '}'
+    pub fn loop_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedLoopExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("loop"),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull AST::Block out of the fifth Output::AST.
+            let body = variant_value!(
+                variant_value!(parser_result_values.remove(4), Output::AST),
+                AST::Block
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Loop(body))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses forexpression =
+    ///     | 'for' newlines? iterarguments newlines? '{' newlines? expressions newlines? '}'
+    pub fn for_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedForExpression, span));
+
+        // Get parser result.
+        // The iteration arguments are parsed as a condition, so they leave out object literals.
+        let parser_result = parse!(
+            combinator,
+            s!("for"),
+            opt!(f!(newlines)),
+            CombinatorArg::Rule((
+                "iter_arguments",
+                Parser::iter_arguments as _,
+                &vec![CombinatorArg::Flag(Parser::BEFORE_BLOCK)],
+            )),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Iter out of the third Output::AST.
+            let iter = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Iter
+            );
+
+            // Pull AST::Block out of the seventh Output::AST.
+            let body = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Block
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::For(Box::new(
+                For { iter, body },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses whileexpression =
+    ///     | 'while' newlines? condition newlines? '{' newlines? expressions newlines? '}'
+    pub fn while_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedWhileExpression, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("while"),
+            opt!(f!(newlines)),
+            f!(condition),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(expressions),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Condition::* out of the third Output::AST.
+            let condition = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Condition
+            );
+
+            // Pull AST::Block out of the seventh Output::AST.
+            let body = variant_value!(
+                variant_value!(parser_result_values.remove(5), Output::AST),
+                AST::Block
+            );
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::While(Box::new(
+                While { condition, body },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses conditionalexpression =
+    ///     | ifexpression
+    ///     | matchexpression
+    ///     | loopexpression
+    ///     | forexpression
+    ///     | whileexpression
+    pub fn conditional_expression<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedConditionalExpression,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(if_expression),
+            f!(match_expression),
+            f!(loop_expression),
+            f!(for_expression),
+            f!(while_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull value field out of Output::Alt.
+            let (value, _) = variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+
+            result = Ok(array.remove(0));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typegenericargument =
+    ///     | identifier (newlines? ':' newlines? typebinop)?
+    pub fn type_generic_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeGenericArgument,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(identifier),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_binop)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let name = Parser::get_terminal_value(parser_result_values.remove(0));
+
+            // Get the bound if it exists.
+            let bound = match parser_result_values.remove(0) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            // The generic parameter is merged by typegenericarguments.
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![GenericParam { name, bound }],
+                parents: vec![],
+                fields: vec![],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typegenericarguments =
+    ///     | '[' newlines? typegenericargument (comma typegenericargument)* comma? newlines? ']'
+    pub fn type_generic_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeGenericArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("["),
+            opt!(f!(newlines)),
+            f!(type_generic_argument),
+            optmore!(f!(comma), f!(type_generic_argument)),
+            opt!(f!(comma)),
+            opt!(f!(newlines)),
+            s!("]")
+        );
+
+        // Holds generic parameters.
+        let mut generics = vec![];
+
+        // Pulls GenericParam out of a partial TypeDecl.
+        let get_generic = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeDecl)
+                .generics
+                .remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first generic parameter.
+            generics.push(get_generic(parser_result_values.remove(2)));
+
+            // Get subsequent generic parameters.
+            let output = parser_result_values.remove(2);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the generic parameter out of the second Output::AST.
+                    generics.push(get_generic(values.remove(1)));
+                }
+            }
+
+            // The generic parameters are merged by the declaration.
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics,
+                parents: vec![],
+                fields: vec![],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typeparentarguments =
+    ///     | '(' newlines? typeexpression (comma typeexpression)* comma? newlines? ')'
+    ///     | typeexpression
+    pub fn type_parent_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeParentArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                s!("("),
+                opt!(f!(newlines)),
+                f!(type_expression),
+                optmore!(f!(comma), f!(type_expression)),
+                opt!(f!(comma)),
+                opt!(f!(newlines)),
+                s!(")")
+            ),
+            f!(type_expression)
+        );
+
+        // Holds parent types.
+        let mut parents = vec![];
+
+        // Pulls TypeExpr::* out of Output::AST.
+        let get_parent = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeExpr)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            match index {
+                // Parenthesized parents.
+                0 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    // Get the first parent.
+                    parents.push(get_parent(values.remove(2)));
+
+                    // Get subsequent parents.
+                    let output = values.remove(2);
+                    if output != Output::Empty {
+                        for values_enum in variant_value!(output, Output::Values) {
+                            // Get the parent out of the second Output::AST.
+                            parents.push(get_parent(
+                                variant_value!(values_enum, Output::Values).remove(1),
+                            ));
+                        }
+                    }
+                }
+                // Single parent.
+                _ => parents.push(get_parent(value)),
+            }
+
+            // The parents are merged by typecompletearguments.
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                parents,
+                fields: vec![],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typecompletearguments =
+    ///     | typegenericarguments? (newlines? ':' newlines? typeparentarguments)?
+    pub fn type_complete_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedTypeCompleteArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(f!(type_generic_arguments)),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_parent_arguments)
+            )
+        );
+
+        // Pulls the partial TypeDecl out of Output::AST.
+        let get_decl = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeDecl)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the generic parameters if they exist.
+            let generics = match parser_result_values.remove(0) {
+                Output::Empty => vec![],
+                output => get_decl(variant_value!(output, Output::Values).remove(0)).generics,
+            };
+
+            // Get the parents if they exist.
+            let parents = match parser_result_values.remove(0) {
+                Output::Empty => vec![],
+                output => get_decl(variant_value!(output, Output::Values).remove(3)).parents,
+            };
+
+            // The arguments are merged by type.
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics,
+                parents,
+                fields: vec![],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses field =
+    ///     | identifier (newlines? ':' newlines? typeexpression)? (newlines? '=' newlines? blockexpression)?
+    pub fn field<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedField, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(identifier),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_expression)
+            ),
+            opt!(
+                opt!(f!(newlines)),
+                s!("="),
+                opt!(f!(newlines)),
+                f!(block_expression)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let name = Parser::get_terminal_value(parser_result_values.remove(0));
+
+            // Get the type if it exists.
+            let ty = match parser_result_values.remove(0) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            // Get the default value if it exists.
+            let default = match parser_result_values.remove(0) {
+                Output::Empty => None,
+                output => Some(variant_value!(
+                    variant_value!(output, Output::Values).remove(3),
+                    Output::AST
+                )),
+            };
+
+            result = Ok(Output::AST(AST::Field(Box::new(Field {
+                public: false,
+                mutable: false,
+                name,
+                ty,
+                default,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typefield =
+    ///     | 'pub'? newlines? (('let' | 'var') newlines?)? field
+    pub fn type_field<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeField, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("pub")),
+            opt!(f!(newlines)),
+            opt!(alt!(s!("let"), s!("var")), opt!(f!(newlines))),
+            f!(field)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the field is public.
+            let public = parser_result_values.remove(0) != Output::Empty;
+
+            // Check if the field is mutable.
+            let mutable = match parser_result_values.remove(1) {
+                Output::Empty => false,
+                output => {
+                    // Pull index field out of the first Output::Alt.
+                    let (_, index) = variant_fields!(
+                        variant_value!(output, Output::Values).remove(0),
+                        Output::Alt,
+                        { value, index }
+                    );
+                    index == 1
+                }
+            };
+
+            // Pull Field out of the fourth Output::AST.
+            let mut field = variant_value!(
+                variant_value!(parser_result_values.remove(1), Output::AST),
+                AST::Field
+            );
+
+            field.public = public;
+            field.mutable = mutable;
+
+            result = Ok(Output::AST(AST::Field(field)));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses typefields =
+    ///     | typefield (comma typefield)* comma?
+    pub fn type_fields<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeFields, span));
+
+        // Recover from errors in the fields of the type body.
+        if combinator.is_recovering() {
+            let fields = Parser::parse_fields(combinator);
+
+            if fields.is_empty() {
+                // Revert advancement.
+                combinator.set_cursor(cursor);
+                return result;
+            }
+
+            // The fields are merged by type.
+            return Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                parents: vec![],
+                fields,
+            })));
+        }
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(type_field),
+            optmore!(f!(comma), f!(type_field)),
+            opt!(f!(comma))
+        );
+
+        // Holds fields.
+        let mut fields = vec![];
+
+        // Pulls Field out of Output::AST.
+        let get_field =
+            |output: Output<AST>| *variant_value!(variant_value!(output, Output::AST), AST::Field);
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first field.
+            fields.push(get_field(parser_result_values.remove(0)));
+
+            // Get subsequent fields.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the field out of the second Output::AST.
+                    fields.push(get_field(values.remove(1)));
+                }
+            }
+
+            // The fields are merged by type.
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                parents: vec![],
+                fields,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses type =
+    ///     | 'pub'? newlines? 'type' newlines? identifier typecompletearguments ('{' newlines? typefields? newlines? '}')?
+    pub fn type_declaration<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedTypeDeclaration, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("pub")),
+            opt!(f!(newlines)),
+            s!("type"),
+            opt!(f!(newlines)),
+            f!(identifier),
+            f!(type_complete_arguments),
+            opt!(
+                s!("{"),
+                opt!(f!(newlines)),
+                opt!(f!(type_fields)),
+                opt!(f!(newlines)),
+                s!("}")
+            )
+        );
+
+        // Pulls the partial TypeDecl out of Output::AST.
+        let get_decl = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::TypeDecl)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the type is public.
+            let public = parser_result_values.remove(0) != Output::Empty;
+
+            let name = Parser::get_terminal_value(parser_result_values.remove(3));
+
+            // Pull the generic parameters and parents out of the sixth Output::AST.
+            let TypeDecl {
+                generics, parents, ..
+            } = get_decl(parser_result_values.remove(3));
+
+            // Get the fields if they exist.
+            let fields = match parser_result_values.remove(3) {
+                Output::Empty => vec![],
+                output => match variant_value!(output, Output::Values).remove(2) {
+                    Output::Empty => vec![],
+                    output => get_decl(variant_value!(output, Output::Values).remove(0)).fields,
+                },
+            };
+
+            result = Ok(Output::AST(AST::TypeDecl(TypeDecl {
+                public,
+                name,
+                generics,
+                parents,
+                fields,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvariantfield =
+    ///     | identifier (newlines? ':' newlines? typeexpression)?
+    pub fn enum_variant_field<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedEnumVariantField, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(identifier),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_expression)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            let name = Parser::get_terminal_value(parser_result_values.remove(0));
+
+            // Get the type if it exists.
+            let ty = match parser_result_values.remove(0) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            // The field is merged by enumvariantfields.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants: vec![EnumVariant::Struct(String::new(), vec![(name, ty)])],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvariantfields =
+    ///     | enumvariantfield (comma enumvariantfield)* comma?
+    pub fn enum_variant_fields<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedEnumVariantFields, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(enum_variant_field),
+            optmore!(f!(comma), f!(enum_variant_field)),
+            opt!(f!(comma))
+        );
+
+        // Holds fields.
+        let mut fields = vec![];
+
+        // Pulls the field out of a partial EnumVariant::Struct.
+        let get_field = |output: Output<AST>| {
+            let mut decl = variant_value!(variant_value!(output, Output::AST), AST::EnumDecl);
+            match decl.variants.remove(0) {
+                EnumVariant::Struct(_, mut fields) => fields.remove(0),
+                _ => unreachable!("Entered the wrong variant"),
+            }
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first field.
+            fields.push(get_field(parser_result_values.remove(0)));
+
+            // Get subsequent fields.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the field out of the second Output::AST.
+                    fields.push(get_field(values.remove(1)));
+                }
+            }
+
+            // The variant name is set by enumvariant.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants: vec![EnumVariant::Struct(String::new(), fields)],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvarianttupleargument =
+    ///     | typeexpression
+    ///     | noname
+    pub fn enum_variant_tuple_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedEnumVariantTupleArgument,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(combinator, f!(type_expression), f!(no_name));
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let ty = match index {
+                // Pull TypeExpr::* out of Output::AST.
+                0 => Some(variant_value!(
+                    variant_value!(value, Output::AST),
+                    AST::TypeExpr
+                )),
+                // Unnamed type.
+                _ => None,
+            };
+
+            // The argument is merged by enumvarianttuplearguments.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants: vec![EnumVariant::Tuple(String::new(), vec![ty])],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvarianttuplearguments =
+    ///     | enumvarianttupleargument (comma enumvarianttupleargument)* comma?
+    pub fn enum_variant_tuple_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedEnumVariantTupleArguments,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(enum_variant_tuple_argument),
+            optmore!(f!(comma), f!(enum_variant_tuple_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds argument types.
+        let mut types = vec![];
+
+        // Pulls the argument type out of a partial EnumVariant::Tuple.
+        let get_type = |output: Output<AST>| {
+            let mut decl = variant_value!(variant_value!(output, Output::AST), AST::EnumDecl);
+            match decl.variants.remove(0) {
+                EnumVariant::Tuple(_, mut types) => types.remove(0),
+                _ => unreachable!("Entered the wrong variant"),
+            }
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first argument.
+            types.push(get_type(parser_result_values.remove(0)));
+
+            // Get subsequent arguments.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the argument out of the second Output::AST.
+                    types.push(get_type(values.remove(1)));
+                }
+            }
+
+            // The variant name is set by enumvariant.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants: vec![EnumVariant::Tuple(String::new(), types)],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvariant =
+    ///     | identifier '{' newlines? enumvariantfields newlines? '}'
+    ///     | identifier '(' newlines? enumvarianttuplearguments newlines? ')'
+    ///     | identifier
+    pub fn enum_variant<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedEnumVariant, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            parse!(
+                f!(identifier),
+                s!("{"),
+                opt!(f!(newlines)),
+                f!(enum_variant_fields),
+                opt!(f!(newlines)),
+                s!("}")
+            ),
+            parse!(
+                f!(identifier),
+                s!("("),
+                opt!(f!(newlines)),
+                f!(enum_variant_tuple_arguments),
+                opt!(f!(newlines)),
+                s!(")")
+            ),
+            f!(identifier)
+        );
+
+        // Pulls the partial EnumVariant::* out of Output::AST.
+        let get_variant = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::EnumDecl)
+                .variants
+                .remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let variant = match index {
+                // Unit variant.
+                2 => EnumVariant::Unit(Parser::get_terminal_value(value)),
+                // Struct-like and tuple-like variants.
+                _ => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let name = Parser::get_terminal_value(values.remove(0));
+
+                    // Set the name of the variant in the fourth Output::AST.
+                    match get_variant(values.remove(2)) {
+                        EnumVariant::Struct(_, fields) => EnumVariant::Struct(name, fields),
+                        EnumVariant::Tuple(_, types) => EnumVariant::Tuple(name, types),
+                        EnumVariant::Unit(_) => unreachable!("Entered the wrong variant"),
+                    }
+                }
+            };
+
+            // The variant is merged by enumvariants.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants: vec![variant],
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enumvariants =
+    ///     | enumvariant (comma enumvariant)* comma?
+    pub fn enum_variants<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedEnumVariants, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(enum_variant),
+            optmore!(f!(comma), f!(enum_variant)),
+            opt!(f!(comma))
+        );
+
+        // Holds variants.
+        let mut variants = vec![];
+
+        // Pulls EnumVariant::* out of a partial EnumDecl.
+        let get_variant = |output: Output<AST>| {
+            variant_value!(variant_value!(output, Output::AST), AST::EnumDecl)
+                .variants
+                .remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first variant.
+            variants.push(get_variant(parser_result_values.remove(0)));
+
+            // Get subsequent variants.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the variant out of the second Output::AST.
+                    variants.push(get_variant(values.remove(1)));
+                }
+            }
+
+            // The variants are merged by enum.
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: String::new(),
+                generics: vec![],
+                variants,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses enum =
+    ///     | 'pub'? newlines? 'enum' newlines? identifier typegenericarguments? newlines? '{' newlines? enumvariants newlines? '}'
+    pub fn enum_declaration<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedEnumDeclaration, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("pub")),
+            opt!(f!(newlines)),
+            s!("enum"),
+            opt!(f!(newlines)),
+            f!(identifier),
+            opt!(f!(type_generic_arguments)),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            f!(enum_variants),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the enum is public.
+            let public = parser_result_values.remove(0) != Output::Empty;
+
+            let name = Parser::get_terminal_value(parser_result_values.remove(3));
+
+            // Get the generic parameters if they exist.
+            let generics = match parser_result_values.remove(3) {
+                Output::Empty => vec![],
+                output => {
+                    // Pull the generic parameters out of the partial TypeDecl.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(0),
+                        Output::AST
+                    );
+                    variant_value!(ast, AST::TypeDecl).generics
+                }
+            };
+
+            // Pull the variants out of the tenth Output::AST.
+            let variants = variant_value!(
+                variant_value!(parser_result_values.remove(6), Output::AST),
+                AST::EnumDecl
+            )
+            .variants;
+
+            result = Ok(Output::AST(AST::EnumDecl(EnumDecl {
+                public,
+                name,
+                generics,
+                variants,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses functionargument =
+    ///     | lhstuplepattern
+    ///     | lhsdictpattern
+    ///     | lhslistpattern
+    ///     | identifier '?'? (newlines? ':' newlines? typeexpression)? (newlines? '=' newlines? blockexpression)?
+    pub fn function_argument<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedFunctionArgument, span));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            f!(lhs_tuple_pattern),
+            f!(lhs_dict_pattern),
+            f!(lhs_list_pattern),
+            parse!(
+                f!(identifier),
+                opt!(s!("?")),
+                opt!(
+                    opt!(f!(newlines)),
+                    s!(":"),
+                    opt!(f!(newlines)),
+                    f!(type_expression)
+                ),
+                opt!(
+                    opt!(f!(newlines)),
+                    s!("="),
+                    opt!(f!(newlines)),
+                    f!(block_expression)
+                )
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let param = match index {
+                // Named parameter.
+                3 => {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(value, Output::Values);
+
+                    let name = Parser::get_terminal_value(values.remove(0));
+
+                    // Check if the parameter is optional.
+                    let optional = values.remove(0) != Output::Empty;
+
+                    // Get the type if it exists.
+                    let ty = match values.remove(0) {
+                        Output::Empty => None,
+                        output => {
+                            // Pull TypeExpr::* out of the fourth Output::AST.
+                            let ast = variant_value!(
+                                variant_value!(output, Output::Values).remove(3),
+                                Output::AST
+                            );
+                            Some(variant_value!(ast, AST::TypeExpr))
+                        }
+                    };
+
+                    // Get the default value if it exists.
+                    let default = match values.remove(0) {
+                        Output::Empty => None,
+                        output => Some(variant_value!(
+                            variant_value!(output, Output::Values).remove(3),
+                            Output::AST
+                        )),
+                    };
+
+                    Param::Named {
+                        name,
+                        ty,
+                        default,
+                        optional,
+                    }
+                }
+                // Destructured parameter.
+                _ => {
+                    let pat = variant_value!(variant_value!(value, Output::AST), AST::Pat);
+                    Param::Pat(variant_value!(pat, Pat::Container))
+                }
+            };
+
+            // The parameter is merged by functionarguments.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Function(
+                Box::new(FunctionDecl {
+                    public: false,
+                    name: None,
+                    generics: vec![],
+                    params: vec![param],
+                    return_type: None,
+                    body: vec![],
+                }),
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses functionarguments =
+    ///     | functionargument (comma functionargument)* comma?
+    pub fn function_arguments<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedFunctionArguments, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(function_argument),
+            optmore!(f!(comma), f!(function_argument)),
+            opt!(f!(comma))
+        );
+
+        // Holds parameters.
+        let mut params = vec![];
+
+        // Pulls Param out of a partial SimpleExpr::Function.
+        let get_param = |output: Output<AST>| {
+            let expr = variant_value!(variant_value!(output, Output::AST), AST::SimpleExpr);
+            variant_value!(expr, SimpleExpr::Function).params.remove(0)
+        };
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the first parameter.
+            params.push(get_param(parser_result_values.remove(0)));
+
+            // Get subsequent parameters.
+            let output = parser_result_values.remove(0);
+            if output != Output::Empty {
+                // Pull array out of Output::Values.
+                let values = variant_value!(output, Output::Values);
+
+                for values_enum in values {
+                    // Pull array out of Output::Values.
+                    let mut values = variant_value!(values_enum, Output::Values);
+
+                    // Get the parameter out of the second Output::AST.
+                    params.push(get_param(values.remove(1)));
+                }
+            }
+
+            // The parameters are merged by function and closure.
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Function(
+                Box::new(FunctionDecl {
+                    public: false,
+                    name: None,
+                    generics: vec![],
+                    params,
+                    return_type: None,
+                    body: vec![],
+                }),
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses function =
+    ///     | 'pub'? newlines? 'fun' newlines? (identifier | operator)? typegenericarguments? newlines? '(' newlines? functionarguments? newlines? ')' (newlines? '->' newlines? typeexpression)? newlines? '{' newlines? expressions? newlines? '}'
+    pub fn function<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedFunction, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("pub")),
+            opt!(f!(newlines)),
+            s!("fun"),
+            opt!(f!(newlines)),
+            opt!(alt!(f!(identifier), f!(operator))),
+            opt!(f!(type_generic_arguments)),
+            opt!(f!(newlines)),
+            s!("("),
+            opt!(f!(newlines)),
+            opt!(f!(function_arguments)),
+            opt!(f!(newlines)),
+            s!(")"),
+            opt!(
+                opt!(f!(newlines)),
+                s!("->"),
+                opt!(f!(newlines)),
+                f!(type_expression)
+            ),
+            opt!(f!(newlines)),
+            s!("{"),
+            opt!(f!(newlines)),
+            opt!(f!(expressions)),
+            opt!(f!(newlines)),
+            s!("}")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the function is public.
+            let public = parser_result_values.remove(0) != Output::Empty;
+
+            // Get the name if it exists.
+            let name = match parser_result_values.remove(3) {
+                Output::Empty => None,
+                output => {
+                    // Pull value field out of the first Output::Alt.
+                    let (value, _) = variant_fields!(
+                        variant_value!(output, Output::Values).remove(0),
+                        Output::Alt,
+                        { value, index }
+                    );
+                    let value = variant_value!(*value, Output::Values).remove(0);
+                    Some(Parser::get_terminal_value(value))
+                }
+            };
+
+            // Get the generic parameters if they exist.
+            let generics = match parser_result_values.remove(3) {
+                Output::Empty => vec![],
+                output => {
+                    // Pull the generic parameters out of the partial TypeDecl.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(0),
+                        Output::AST
+                    );
+                    variant_value!(ast, AST::TypeDecl).generics
+                }
+            };
+
+            // Get the parameters if they exist.
+            let params = Parser::get_params(parser_result_values.remove(6));
+
+            // Get the return type if it exists.
+            let return_type = match parser_result_values.remove(8) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            // Get the body if it exists.
+            let body = Parser::get_body(parser_result_values.remove(11));
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Function(
+                Box::new(FunctionDecl {
+                    public,
+                    name,
+                    generics,
+                    params,
+                    return_type,
+                    body,
+                }),
+            ))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses closure =
+    ///     | '(' newlines? functionarguments? newlines? ')' newlines? '=>' newlines? '{' newlines? expressions? newlines? '}'
+    ///     | '(' newlines? functionarguments? newlines? ')' newlines? '=>' newlines? simpleexpression
+    pub fn closure<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedClosure, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            s!("("),
+            opt!(f!(newlines)),
+            opt!(f!(function_arguments)),
+            opt!(f!(newlines)),
+            s!(")"),
+            opt!(f!(newlines)),
+            s!("=>"),
+            opt!(f!(newlines)),
+            alt!(
+                parse!(
+                    s!("{"),
+                    opt!(f!(newlines)),
+                    opt!(f!(expressions)),
+                    opt!(f!(newlines)),
+                    s!("}")
+                ),
+                f!(simple_expression)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Get the parameters if they exist.
+            let params = Parser::get_params(parser_result_values.remove(2));
+
+            // Pull fields out of the ninth Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result_values.remove(7), Output::Alt, { value, index });
+
+            // Pull array out of Output::Values.
+            let mut array = variant_value!(*value, Output::Values);
+            let value = array.remove(0);
+
+            let body = match index {
+                // Block body.
+                0 => Parser::get_body(variant_value!(value, Output::Values).remove(2)),
+                // Single expression.
+                _ => vec![variant_value!(value, Output::AST)],
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Closure(Box::new(
+                Closure { params, body },
+            )))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses subject =
+    ///     | 'pub'? newlines? ('let' | 'var' | 'const') newlines? lhspattern (newlines? ':' newlines? typeexpression)? (newlines? '=' newlines? blockexpression)?
+    pub fn subject<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedSubject, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            opt!(s!("pub")),
+            opt!(f!(newlines)),
+            alt!(s!("let"), s!("var"), s!("const")),
+            opt!(f!(newlines)),
+            f!(lhs_pattern),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_expression)
+            ),
+            opt!(
+                opt!(f!(newlines)),
+                s!("="),
+                opt!(f!(newlines)),
+                f!(block_expression)
+            )
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Check if the binding is public.
+            let public = parser_result_values.remove(0) != Output::Empty;
+
+            // Pull index field out of the third Output::Alt.
+            let (_, index) =
+                variant_fields!(parser_result_values.remove(1), Output::Alt, { value, index });
+
+            let kind = match index {
+                0 => BindingKind::Let,
+                1 => BindingKind::Var,
+                _ => BindingKind::Const,
+            };
+
+            // Pull Pat::* out of the fifth Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(2), Output::AST),
+                AST::Pat
+            );
+
+            // Get the type if it exists.
+            let ty = match parser_result_values.remove(2) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            // Get the value if it exists.
+            let value = match parser_result_values.remove(2) {
+                Output::Empty => None,
+                output => Some(Box::new(variant_value!(
+                    variant_value!(output, Output::Values).remove(3),
+                    Output::AST
+                ))),
+            };
+
+            result = Ok(Output::AST(AST::Expr(Expr::Binding(Binding {
+                public,
+                kind,
+                pat,
+                ty,
+                value,
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses assignmentoperator =
+    ///     | '='
+    ///     | '.='
+    ///     | '+='
+    ///     | '-='
+    ///     | '/='
+    ///     | '÷='
+    ///     | '*='
+    ///     | '×='
+    ///     | '^='
+    ///     | '~='
+    ///     | '|='
+    ///     | '&='
+    pub fn assignment_operator<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> = Err(ParserError::new(
+            ErrorKind::ExpectedAssignmentOperator,
+            span,
+        ));
+
+        // Get parser result.
+        let parser_result = alt!(
+            combinator,
+            s!("="),
+            parse!(s!("."), f!(no_space), s!("=")),
+            s!("+="),
+            s!("-="),
+            s!("/="),
+            s!("÷="),
+            s!("*="),
+            s!("×="),
+            s!("^="),
+            s!("~="),
+            s!("|="),
+            s!("&=")
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull fields out of Output::Alt.
+            let (value, index) =
+                variant_fields!(parser_result.unwrap(), Output::Alt, { value, index });
+
+            let operator = match index {
+                // The lexer splits `.=` into `.` and `=`.
+                1 => ".=".into(),
+                // Pull the operator out of Output::Str.
+                _ => variant_value!(
+                    variant_value!(*value, Output::Values).remove(0),
+                    Output::Str
+                ),
+            };
+
+            result = Ok(Output::AST(AST::SimpleExpr(SimpleExpr::Terminal {
+                kind: TokenKind::Operator,
+                value: operator,
+            })));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
+
+        result
+    }
+
+    /// Parses assignment =
+    ///     | lhspattern (newlines? ':' newlines? typeexpression)? assignmentoperator newlines? blockexpression
+    pub fn assignment<'a>(
+        _args: &[CombinatorArg<'a, AST>],
+        combinator: &mut Combinator<AST>,
+    ) -> Result<Output<AST>, ParserError> {
+        // Get the cursor and span.
+        let cursor = combinator.get_cursor();
+        let span = combinator.get_span();
+
+        // Holds the returning result.
+        let mut result: Result<Output<AST>, ParserError> =
+            Err(ParserError::new(ErrorKind::ExpectedAssignment, span));
+
+        // Get parser result.
+        let parser_result = parse!(
+            combinator,
+            f!(lhs_pattern),
+            opt!(
+                opt!(f!(newlines)),
+                s!(":"),
+                opt!(f!(newlines)),
+                f!(type_expression)
+            ),
+            f!(assignment_operator),
+            opt!(f!(newlines)),
+            f!(block_expression)
+        );
+
+        // Check if parser result is OK.
+        if parser_result.is_ok() {
+            // Pull array out of Output::Values.
+            let mut parser_result_values = variant_value!(parser_result.unwrap(), Output::Values);
+
+            // Pull Pat::* out of the first Output::AST.
+            let pat = variant_value!(
+                variant_value!(parser_result_values.remove(0), Output::AST),
+                AST::Pat
+            );
+
+            // Get the type if it exists.
+            let ty = match parser_result_values.remove(0) {
+                Output::Empty => None,
+                output => {
+                    // Pull TypeExpr::* out of the fourth Output::AST.
+                    let ast = variant_value!(
+                        variant_value!(output, Output::Values).remove(3),
+                        Output::AST
+                    );
+                    Some(variant_value!(ast, AST::TypeExpr))
+                }
+            };
+
+            let op = Parser::get_terminal_value(parser_result_values.remove(0));
+
+            // Pull the value out of the fifth Output::AST.
+            let value = variant_value!(parser_result_values.remove(1), Output::AST);
+
+            result = Ok(Output::AST(AST::Expr(Expr::Assign(Assign {
+                pat,
+                ty,
+                op,
+                value: Box::new(value),
+            }))));
+        } else {
+            // Revert advancement.
+            combinator.set_cursor(cursor);
+        }
 
         result
     }
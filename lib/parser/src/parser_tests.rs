@@ -10,8 +10,13 @@ mod tests {
         macros,
         parser::Parser,
     };
-    use astro_codegen::asts::{SimpleExpr, AST};
-    use astro_lexer::{Lexer, Token, TokenKind};
+    use astro_codegen::asts::{
+        Assign, Binding, BindingKind, CallArg, Closure, Compr, ComprKind, Condition, ContainerPat,
+        EnumDecl, EnumVariant, Expr, Field, Fixity, For, FunctionDecl, GenericParam, If, Iter,
+        IterArg, Match, MatchArm, MatchContainerPat, MatchPat, MatchPatArg, Object, Param, Pat,
+        PatArg, PatKind, Range, SimpleExpr, TypeDecl, TypeExpr, TypeRelation, While, AST,
+    };
+    use astro_lexer::{Lexer, Span, Token, TokenKind};
 
     // Output::AST(AST::SimpleExpr(SimpleExpr::List(vec![])))
     // println!("parser = {:?}", combinator_result_2);
@@ -21,6 +26,29 @@ mod tests {
         Combinator::new(tokens)
     }
 
+    fn get_terminal(kind: TokenKind, value: &str) -> SimpleExpr {
+        SimpleExpr::Terminal {
+            kind,
+            value: value.into(),
+        }
+    }
+
+    fn get_binary_op(op: &str, lhs: SimpleExpr, rhs: SimpleExpr) -> SimpleExpr {
+        SimpleExpr::BinaryOp {
+            op: op.into(),
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }
+    }
+
+    fn get_unary_op(op: &str, fixity: Fixity, expr: SimpleExpr) -> SimpleExpr {
+        SimpleExpr::UnaryOp {
+            op: op.into(),
+            fixity,
+            expr: Box::new(expr),
+        }
+    }
+
     #[test]
     fn newlines() {
         // Sinle newline.
@@ -262,7 +290,9 @@ mod tests {
             combinator_result_4,
             Err(ParserError {
                 error: ErrorKind::ExpectedTupleArguments,
-                column: 0
+                span: Span::new(0, 1, 1, 1),
+                expected: vec![],
+                found: None
             })
         );
     }
@@ -335,4 +365,1832 @@ mod tests {
             ))]))
         );
     }
+
+    #[test]
+    fn dict_arguments() {
+        // Key and value.
+        let combinator = &mut get_combinator_for_code("a: 1".into());
+        let combinator_result_1 = parse!(combinator, f!(dict_arguments));
+
+        // Shorthand keys with trailing comma.
+        let combinator = &mut get_combinator_for_code("a, b,".into());
+        let combinator_result_2 = parse!(combinator, f!(dict_arguments));
+
+        // Newlines around colons and commas.
+        let combinator = &mut get_combinator_for_code("'a'\n:\n1\n,\nb".into());
+        let combinator_result_3 = parse!(combinator, f!(dict_arguments));
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![(
+                    get_terminal(TokenKind::Identifier, "a"),
+                    Some(get_terminal(TokenKind::IntegerDecimalLiteral, "1"))
+                )])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![
+                    (get_terminal(TokenKind::Identifier, "a"), None),
+                    (get_terminal(TokenKind::Identifier, "b"), None)
+                ])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![
+                    (
+                        get_terminal(TokenKind::StringLiteral, "a"),
+                        Some(get_terminal(TokenKind::IntegerDecimalLiteral, "1"))
+                    ),
+                    (get_terminal(TokenKind::Identifier, "b"), None)
+                ])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn dict_literal() {
+        // Key and value pairs.
+        let combinator = &mut get_combinator_for_code("{ title: 'Up', year: 2009 }".into());
+        let combinator_result_1 = parse!(combinator, f!(dict_literal));
+
+        // Nested dict with newlines.
+        let combinator =
+            &mut get_combinator_for_code("{\n    mum: {\n        age: 42\n    }\n}".into());
+        let combinator_result_2 = parse!(combinator, f!(dict_literal));
+
+        // No argument.
+        let combinator = &mut get_combinator_for_code("{}".into());
+        let combinator_result_3 = parse!(combinator, f!(dict_literal));
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![
+                    (
+                        get_terminal(TokenKind::Identifier, "title"),
+                        Some(get_terminal(TokenKind::StringLiteral, "Up"))
+                    ),
+                    (
+                        get_terminal(TokenKind::Identifier, "year"),
+                        Some(get_terminal(TokenKind::IntegerDecimalLiteral, "2009"))
+                    )
+                ])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![(
+                    get_terminal(TokenKind::Identifier, "mum"),
+                    Some(SimpleExpr::Dict(vec![(
+                        get_terminal(TokenKind::Identifier, "age"),
+                        Some(get_terminal(TokenKind::IntegerDecimalLiteral, "42"))
+                    )]))
+                )])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Dict(vec![])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn object_literal() {
+        // Fields with and without values.
+        let combinator = &mut get_combinator_for_code("Person { name: 'Jane', age }".into());
+        let combinator_result_1 = parse!(combinator, f!(object_literal));
+
+        // Type arguments and newlines.
+        let combinator = &mut get_combinator_for_code("Pair[Int, Str] {\n    first: 1,\n}".into());
+        let combinator_result_2 = parse!(combinator, f!(object_literal));
+
+        // No argument.
+        let combinator = &mut get_combinator_for_code("Unit {}".into());
+        let combinator_result_3 = parse!(combinator, f!(object_literal));
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Object(Object {
+                    name: "Person".into(),
+                    type_args: vec![],
+                    fields: vec![
+                        (
+                            "name".into(),
+                            Some(get_terminal(TokenKind::StringLiteral, "Jane"))
+                        ),
+                        ("age".into(), None)
+                    ]
+                })
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Object(Object {
+                    name: "Pair".into(),
+                    type_args: vec![TypeExpr::Name("Int".into()), TypeExpr::Name("Str".into())],
+                    fields: vec![(
+                        "first".into(),
+                        Some(get_terminal(TokenKind::IntegerDecimalLiteral, "1"))
+                    )]
+                })
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Object(Object {
+                    name: "Unit".into(),
+                    type_args: vec![],
+                    fields: vec![]
+                })
+            ))]))
+        );
+    }
+
+    #[test]
+    fn comprehension() {
+        // List comprehension.
+        let combinator = &mut get_combinator_for_code("[x | x in xs]".into());
+        let combinator_result_1 = parse!(combinator, f!(comprehension));
+
+        // Generator comprehension with `var`, several arguments and a guard.
+        let combinator = &mut get_combinator_for_code(
+            "(x * 2 | var x in xs and y in a | b\n    where x > y)".into(),
+        );
+        let combinator_result_2 = parse!(combinator, f!(comprehension));
+
+        // Dict comprehension.
+        let combinator = &mut get_combinator_for_code("{ k: v | k in ks }".into());
+        let combinator_result_3 = parse!(combinator, f!(comprehension));
+
+        // List literal with `|` as an infix operator.
+        let combinator = &mut get_combinator_for_code("[x | y]".into());
+        let combinator_result_4 = parse!(combinator, f!(sub_atom));
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Compr(Box::new(Compr {
+                    kind: ComprKind::List,
+                    expr: get_terminal(TokenKind::Identifier, "x"),
+                    value: None,
+                    iter: Iter {
+                        args: vec![IterArg {
+                            mutable: false,
+                            pat: Pat::Ref(get_terminal(TokenKind::Identifier, "x")),
+                            expr: get_terminal(TokenKind::Identifier, "xs"),
+                        }],
+                        guard: None,
+                    },
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Compr(Box::new(Compr {
+                    kind: ComprKind::Generator,
+                    expr: get_binary_op(
+                        "*",
+                        get_terminal(TokenKind::Identifier, "x"),
+                        get_terminal(TokenKind::IntegerDecimalLiteral, "2")
+                    ),
+                    value: None,
+                    iter: Iter {
+                        args: vec![
+                            IterArg {
+                                mutable: true,
+                                pat: Pat::Ref(get_terminal(TokenKind::Identifier, "x")),
+                                expr: get_terminal(TokenKind::Identifier, "xs"),
+                            },
+                            IterArg {
+                                mutable: false,
+                                pat: Pat::Ref(get_terminal(TokenKind::Identifier, "y")),
+                                expr: get_binary_op(
+                                    "|",
+                                    get_terminal(TokenKind::Identifier, "a"),
+                                    get_terminal(TokenKind::Identifier, "b")
+                                ),
+                            }
+                        ],
+                        guard: Some(get_binary_op(
+                            ">",
+                            get_terminal(TokenKind::Identifier, "x"),
+                            get_terminal(TokenKind::Identifier, "y")
+                        )),
+                    },
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Compr(Box::new(Compr {
+                    kind: ComprKind::Dict,
+                    expr: get_terminal(TokenKind::Identifier, "k"),
+                    value: Some(get_terminal(TokenKind::Identifier, "v")),
+                    iter: Iter {
+                        args: vec![IterArg {
+                            mutable: false,
+                            pat: Pat::Ref(get_terminal(TokenKind::Identifier, "k")),
+                            expr: get_terminal(TokenKind::Identifier, "ks"),
+                        }],
+                        guard: None,
+                    },
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::List(vec![get_binary_op(
+                    "|",
+                    get_terminal(TokenKind::Identifier, "x"),
+                    get_terminal(TokenKind::Identifier, "y")
+                )])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn atom() {
+        // Multiline dot notation chain.
+        let combinator = &mut get_combinator_for_code("sentence\n    .trim()\n    .lower()".into());
+        let combinator_result_1 = parse!(combinator, f!(atom));
+
+        // Index and slices.
+        let combinator = &mut get_combinator_for_code("xs[1..3, ..2.., i]".into());
+        let combinator_result_2 = parse!(combinator, f!(atom));
+
+        // Tuple indices.
+        let combinator = &mut get_combinator_for_code("pair.0.1".into());
+        let combinator_result_3 = parse!(combinator, f!(atom));
+
+        // Vectorized call with type and named arguments, then unwrap.
+        let combinator = &mut get_combinator_for_code("f.[Int](x: 1, y)!".into());
+        let combinator_result_4 = parse!(combinator, f!(atom));
+
+        // Calls `expr.name()`.
+        let call_method = |expr: SimpleExpr, name: &str| SimpleExpr::Call {
+            expr: Box::new(SimpleExpr::Dot {
+                expr: Box::new(expr),
+                name: name.into(),
+            }),
+            vectorized: false,
+            type_args: vec![],
+            args: vec![],
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                call_method(
+                    call_method(get_terminal(TokenKind::Identifier, "sentence"), "trim"),
+                    "lower"
+                )
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Index {
+                    expr: Box::new(get_terminal(TokenKind::Identifier, "xs")),
+                    args: vec![
+                        SimpleExpr::Range(Range {
+                            start: Some(Box::new(get_terminal(
+                                TokenKind::IntegerDecimalLiteral,
+                                "1"
+                            ))),
+                            step: None,
+                            end: Some(Box::new(get_terminal(
+                                TokenKind::IntegerDecimalLiteral,
+                                "3"
+                            ))),
+                        }),
+                        SimpleExpr::Range(Range {
+                            start: None,
+                            step: Some(Box::new(get_terminal(
+                                TokenKind::IntegerDecimalLiteral,
+                                "2"
+                            ))),
+                            end: None,
+                        }),
+                        get_terminal(TokenKind::Identifier, "i"),
+                    ]
+                }
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::TupleIndex {
+                    expr: Box::new(SimpleExpr::TupleIndex {
+                        expr: Box::new(get_terminal(TokenKind::Identifier, "pair")),
+                        index: "0".into(),
+                    }),
+                    index: "1".into(),
+                }
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Unwrap(Box::new(SimpleExpr::Call {
+                    expr: Box::new(get_terminal(TokenKind::Identifier, "f")),
+                    vectorized: true,
+                    type_args: vec![TypeExpr::Name("Int".into())],
+                    args: vec![
+                        CallArg {
+                            name: Some("x".into()),
+                            expr: get_terminal(TokenKind::IntegerDecimalLiteral, "1"),
+                        },
+                        CallArg {
+                            name: None,
+                            expr: get_terminal(TokenKind::Identifier, "y"),
+                        },
+                    ],
+                }))
+            ))]))
+        );
+    }
+
+    #[test]
+    fn infix_expression() {
+        // Multiplication binds tighter than addition.
+        let combinator = &mut get_combinator_for_code("3 * f + 2 * (6 + 1)".into());
+        let combinator_result_1 = parse!(combinator, f!(infix_expression));
+
+        // Exponentiation is right associative, subtraction is left associative.
+        let combinator = &mut get_combinator_for_code("a - b - c ^ d ^ e".into());
+        let combinator_result_2 = parse!(combinator, f!(infix_expression));
+
+        // Keyword operators.
+        let combinator = &mut get_combinator_for_code("not a is not b or x not in y and z".into());
+        let combinator_result_3 = parse!(combinator, f!(infix_expression));
+
+        // Prefix and postfix operators.
+        let combinator = &mut get_combinator_for_code("-a * b² << 2".into());
+        let combinator_result_4 = parse!(combinator, f!(infix_expression));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_binary_op(
+                    "+",
+                    get_binary_op("*", int("3"), id("f")),
+                    get_binary_op("*", int("2"), get_binary_op("+", int("6"), int("1")))
+                )
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_binary_op(
+                    "-",
+                    get_binary_op("-", id("a"), id("b")),
+                    get_binary_op("^", id("c"), get_binary_op("^", id("d"), id("e")))
+                )
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_binary_op(
+                    "or",
+                    get_unary_op(
+                        "not",
+                        Fixity::Prefix,
+                        get_binary_op("is not", id("a"), id("b"))
+                    ),
+                    get_binary_op("and", get_binary_op("not in", id("x"), id("y")), id("z"))
+                )
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_binary_op(
+                    "<<",
+                    get_binary_op(
+                        "*",
+                        get_unary_op("-", Fixity::Prefix, id("a")),
+                        get_unary_op("²", Fixity::Postfix, id("b"))
+                    ),
+                    int("2")
+                )
+            ))]))
+        );
+    }
+
+    #[test]
+    fn coefficient_expression() {
+        // Coefficients bind tighter than infix operators.
+        let combinator = &mut get_combinator_for_code("3f + 2(6 + 1)".into());
+        let combinator_result_1 = parse!(combinator, f!(infix_expression));
+
+        // Hexadecimal coefficients need parentheses.
+        let combinator = &mut get_combinator_for_code("(0x344)f".into());
+        let combinator_result_2 = parse!(combinator, f!(coefficient_expression));
+
+        // A hexadecimal literal that ends with a hex digit.
+        let combinator = &mut get_combinator_for_code("0x344f".into());
+        let combinator_result_3 = parse!(combinator, f!(infix_expression));
+
+        // Hexadecimal coefficients without parentheses.
+        let combinator = &mut get_combinator_for_code("0x7f(a)".into());
+        let combinator_result_4 = parse!(combinator, f!(simple_expression));
+        let combinator = &mut get_combinator_for_code("0x7fz".into());
+        let combinator_result_5 = parse!(combinator, f!(simple_expression));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let coeff = |coefficient, expr| SimpleExpr::Coeff {
+            coefficient: Box::new(coefficient),
+            expr: Box::new(expr),
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_binary_op(
+                    "+",
+                    coeff(int("3"), get_terminal(TokenKind::Identifier, "f")),
+                    coeff(int("2"), get_binary_op("+", int("6"), int("1")))
+                )
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(coeff(
+                get_terminal(TokenKind::IntegerHexadecimalLiteral, "344"),
+                get_terminal(TokenKind::Identifier, "f")
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                get_terminal(TokenKind::IntegerHexadecimalLiteral, "344f")
+            ))]))
+        );
+        assert!(combinator_result_4.is_err());
+        assert!(combinator_result_5.is_err());
+    }
+
+    #[test]
+    fn command_notation() {
+        // Identifier argument.
+        let combinator = &mut get_combinator_for_code("print name".into());
+        let combinator_result_1 = parse!(combinator, f!(fix_expression));
+
+        // Comprehension argument.
+        let combinator = &mut get_combinator_for_code("print (x | x in xs)".into());
+        let combinator_result_2 = parse!(combinator, f!(fix_expression));
+
+        // Tuples are call arguments.
+        let combinator = &mut get_combinator_for_code("send (2, 3)".into());
+        let combinator_result_3 = parse!(combinator, f!(fix_expression));
+
+        // Closure argument.
+        let combinator = &mut get_combinator_for_code("print (x, y) => x".into());
+        let combinator_result_4 = parse!(combinator, f!(fix_expression));
+
+        // Ternary operator argument.
+        let combinator = &mut get_combinator_for_code("print (x > y) ? x : y".into());
+        let combinator_result_5 = parse!(combinator, f!(fix_expression));
+
+        // Range argument.
+        let combinator = &mut get_combinator_for_code("print 1..2".into());
+        let combinator_result_6 = parse!(combinator, f!(fix_expression));
+
+        // Symbol argument.
+        let combinator = &mut get_combinator_for_code("print :name".into());
+        let combinator_result_7 = parse!(combinator, f!(fix_expression));
+
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+        let call = |name, args: Vec<SimpleExpr>| SimpleExpr::Call {
+            expr: Box::new(id(name)),
+            vectorized: false,
+            type_args: vec![],
+            args: args
+                .into_iter()
+                .map(|expr| CallArg { name: None, expr })
+                .collect(),
+        };
+        let param = |name: &str| Param::Named {
+            name: name.into(),
+            ty: None,
+            default: None,
+            optional: false,
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![id("name")]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![SimpleExpr::Compr(Box::new(Compr {
+                    kind: ComprKind::Generator,
+                    expr: id("x"),
+                    value: None,
+                    iter: Iter {
+                        args: vec![IterArg {
+                            mutable: false,
+                            pat: Pat::Ref(id("x")),
+                            expr: id("xs"),
+                        }],
+                        guard: None,
+                    },
+                }))]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "send",
+                vec![
+                    get_terminal(TokenKind::IntegerDecimalLiteral, "2"),
+                    get_terminal(TokenKind::IntegerDecimalLiteral, "3")
+                ]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![SimpleExpr::Closure(Box::new(Closure {
+                    params: vec![param("x"), param("y")],
+                    body: vec![AST::SimpleExpr(id("x"))],
+                }))]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_5,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![SimpleExpr::TernaryOp {
+                    condition: Box::new(get_binary_op(">", id("x"), id("y"))),
+                    consequent: Box::new(id("x")),
+                    alternative: Box::new(id("y")),
+                }]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_6,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![SimpleExpr::Range(Range {
+                    start: Some(Box::new(get_terminal(
+                        TokenKind::IntegerDecimalLiteral,
+                        "1"
+                    ))),
+                    step: None,
+                    end: Some(Box::new(get_terminal(
+                        TokenKind::IntegerDecimalLiteral,
+                        "2"
+                    ))),
+                })]
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_7,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(call(
+                "print",
+                vec![SimpleExpr::Symbol("name".into())]
+            )))]))
+        );
+    }
+
+    #[test]
+    fn simple_expression() {
+        // Ternary operator.
+        let combinator = &mut get_combinator_for_code("(a > b) ?\n    a : b".into());
+        let combinator_result_1 = parse!(combinator, f!(simple_expression));
+
+        // Range with a step.
+        let combinator = &mut get_combinator_for_code("0..2..n + 1".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        // Open ranges.
+        let combinator = &mut get_combinator_for_code("(1.., ..n, ..)".into());
+        let combinator_result_3 = parse!(combinator, f!(simple_expression));
+
+        // Spread.
+        let combinator = &mut get_combinator_for_code("[...xs, 1]".into());
+        let combinator_result_4 = parse!(combinator, f!(simple_expression));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+        let range =
+            |start: Option<SimpleExpr>, step: Option<SimpleExpr>, end: Option<SimpleExpr>| {
+                SimpleExpr::Range(Range {
+                    start: start.map(Box::new),
+                    step: step.map(Box::new),
+                    end: end.map(Box::new),
+                })
+            };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::TernaryOp {
+                    condition: Box::new(get_binary_op(">", id("a"), id("b"))),
+                    consequent: Box::new(id("a")),
+                    alternative: Box::new(id("b")),
+                }
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(range(
+                Some(int("0")),
+                Some(int("2")),
+                Some(get_binary_op("+", id("n"), int("1")))
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Tuple(vec![
+                    range(Some(int("1")), None, None),
+                    range(None, None, Some(id("n"))),
+                    range(None, None, None),
+                ])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::List(vec![SimpleExpr::Spread(Box::new(id("xs"))), int("1")])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn type_expression() {
+        // Function type with postfixes, suffixes and relations.
+        let combinator =
+            &mut get_combinator_for_code("(A, List[T]?) -> io.B! where T <: U, |V, W| :: X".into());
+        let combinator_result_1 = parse!(combinator, f!(type_expression));
+
+        // Unions and intersections.
+        let combinator = &mut get_combinator_for_code("A | B | C & D".into());
+        let combinator_result_2 = parse!(combinator, f!(type_expression));
+
+        // Parenthesized, single and empty tuple types.
+        let combinator = &mut get_combinator_for_code("(A | B)? & (C,) & ()".into());
+        let combinator_result_3 = parse!(combinator, f!(type_expression));
+
+        let name = |value: &str| TypeExpr::Name(value.into());
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::TypeExpr(
+                TypeExpr::Function {
+                    params: vec![
+                        name("A"),
+                        TypeExpr::Optional(Box::new(TypeExpr::Generic(
+                            Box::new(name("List")),
+                            vec![name("T")]
+                        ))),
+                    ],
+                    ret: Box::new(TypeExpr::Errable(Box::new(TypeExpr::Dot(
+                        Box::new(name("io")),
+                        "B".into()
+                    )))),
+                    relations: vec![
+                        TypeRelation {
+                            lhs: vec![name("T")],
+                            op: "<:".into(),
+                            rhs: name("U"),
+                        },
+                        TypeRelation {
+                            lhs: vec![name("V"), name("W")],
+                            op: "::".into(),
+                            rhs: name("X"),
+                        },
+                    ],
+                }
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::TypeExpr(
+                TypeExpr::Union(vec![
+                    name("A"),
+                    name("B"),
+                    TypeExpr::Intersection(vec![name("C"), name("D")]),
+                ])
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::TypeExpr(
+                TypeExpr::Intersection(vec![
+                    TypeExpr::Optional(Box::new(TypeExpr::Union(vec![name("A"), name("B")]))),
+                    TypeExpr::Tuple(vec![name("C")]),
+                    TypeExpr::Tuple(vec![]),
+                ])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn lhs_pattern() {
+        // Tuple pattern with rest.
+        let combinator = &mut get_combinator_for_code("(a, ...rest)".into());
+        let combinator_result_1 = parse!(combinator, f!(lhs_pattern));
+
+        // Dict pattern with a mutable nested pattern.
+        let combinator = &mut get_combinator_for_code("{ name,\n  var age: [_, ...] }".into());
+        let combinator_result_2 = parse!(combinator, f!(lhs_pattern));
+
+        // Object pattern.
+        let combinator = &mut get_combinator_for_code("Person(x, y)".into());
+        let combinator_result_3 = parse!(combinator, f!(lhs_pattern));
+
+        // Dereferenced reference with postfixes.
+        let combinator = &mut get_combinator_for_code("*ptr.field[0]".into());
+        let combinator_result_4 = parse!(combinator, f!(lhs_pattern));
+
+        // Creates a pattern argument.
+        let arg = |mutable, rest, name: Option<&str>, pat| PatArg {
+            mutable,
+            rest,
+            name: name.map(Into::into),
+            pat,
+        };
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::Pat(Pat::Container(
+                ContainerPat {
+                    kind: PatKind::Tuple,
+                    args: vec![
+                        arg(false, false, Some("a"), None),
+                        arg(false, true, Some("rest"), None)
+                    ],
+                }
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::Pat(Pat::Container(
+                ContainerPat {
+                    kind: PatKind::Dict,
+                    args: vec![
+                        arg(false, false, Some("name"), None),
+                        arg(
+                            true,
+                            false,
+                            Some("age"),
+                            Some(Pat::Container(ContainerPat {
+                                kind: PatKind::List,
+                                args: vec![
+                                    arg(false, false, Some("_"), None),
+                                    arg(false, true, None, None)
+                                ],
+                            }))
+                        )
+                    ],
+                }
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::Pat(Pat::Object(
+                TypeExpr::Name("Person".into()),
+                ContainerPat {
+                    kind: PatKind::Tuple,
+                    args: vec![
+                        arg(false, false, Some("x"), None),
+                        arg(false, false, Some("y"), None)
+                    ],
+                }
+            )))]))
+        );
+        assert_eq!(
+            combinator_result_4,
+            Ok(Output::Values(vec![Output::AST(AST::Pat(Pat::Ref(
+                get_unary_op(
+                    "*",
+                    Fixity::Prefix,
+                    SimpleExpr::Index {
+                        expr: Box::new(SimpleExpr::Dot {
+                            expr: Box::new(id("ptr")),
+                            name: "field".into(),
+                        }),
+                        args: vec![get_terminal(TokenKind::IntegerDecimalLiteral, "0")],
+                    }
+                )
+            )))]))
+        );
+    }
+
+    #[test]
+    fn match_expression() {
+        // Container, alternative and range patterns.
+        let combinator = &mut get_combinator_for_code(
+            "match object {\n    Some { value } => 0,\n    [1, 2, ..s] => 1,\n    75 | 79 => 2,\n    75 .. 79 => 3,\n}".into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(simple_expression));
+
+        // Type relation and block body.
+        let combinator = &mut get_combinator_for_code("match x { y :: Int => { y; z } }".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        // Rest bindings.
+        let combinator = &mut get_combinator_for_code("(..xs, ...ys)".into());
+        let combinator_result_3 = parse!(combinator, f!(match_pattern));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        // Creates a match pattern argument.
+        let arg = |rest, name: Option<&str>, pat| MatchPatArg {
+            mutable: false,
+            rest,
+            name: name.map(Into::into),
+            pat,
+        };
+
+        // Creates an arm with a single expression.
+        let arm = |pat, value| MatchArm {
+            pat,
+            body: vec![AST::SimpleExpr(int(value))],
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Match(Box::new(Match {
+                    expr: id("object"),
+                    arms: vec![
+                        arm(
+                            MatchPat::Object(
+                                TypeExpr::Name("Some".into()),
+                                MatchContainerPat {
+                                    kind: PatKind::Dict,
+                                    args: vec![arg(false, Some("value"), None)],
+                                }
+                            ),
+                            "0"
+                        ),
+                        arm(
+                            MatchPat::Container(MatchContainerPat {
+                                kind: PatKind::List,
+                                args: vec![
+                                    arg(false, None, Some(MatchPat::Literal(int("1")))),
+                                    arg(false, None, Some(MatchPat::Literal(int("2")))),
+                                    arg(true, Some("s"), None),
+                                ],
+                            }),
+                            "1"
+                        ),
+                        arm(
+                            MatchPat::Alt(vec![
+                                MatchPat::Literal(int("75")),
+                                MatchPat::Literal(int("79"))
+                            ]),
+                            "2"
+                        ),
+                        arm(
+                            MatchPat::Range {
+                                start: int("75"),
+                                end: int("79"),
+                            },
+                            "3"
+                        ),
+                    ],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Match(Box::new(Match {
+                    expr: id("x"),
+                    arms: vec![MatchArm {
+                        pat: MatchPat::Id {
+                            name: "y".into(),
+                            relation: Some(("::".into(), TypeExpr::Name("Int".into()))),
+                        },
+                        body: vec![AST::SimpleExpr(id("y")), AST::SimpleExpr(id("z"))],
+                    }],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::MatchPat(
+                MatchPat::Container(MatchContainerPat {
+                    kind: PatKind::Tuple,
+                    args: vec![arg(true, Some("xs"), None), arg(true, Some("ys"), None)],
+                })
+            ))]))
+        );
+    }
+
+    #[test]
+    fn if_expression() {
+        // A condition that ends in an identifier before its block.
+        let combinator = &mut get_combinator_for_code(
+            "if a > b { a }\nelif let Some(x) = c { x }\nelse { b }".into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(simple_expression));
+
+        // Object literals are still parsed in parentheses.
+        let combinator = &mut get_combinator_for_code("if (p == Point { x }) { p }".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::If(Box::new(If {
+                    condition: Condition::Expr(get_binary_op(">", id("a"), id("b"))),
+                    body: vec![AST::SimpleExpr(id("a"))],
+                    elifs: vec![(
+                        Condition::Binding {
+                            mutable: false,
+                            pat: Pat::Object(
+                                TypeExpr::Name("Some".into()),
+                                ContainerPat {
+                                    kind: PatKind::Tuple,
+                                    args: vec![PatArg {
+                                        mutable: false,
+                                        rest: false,
+                                        name: Some("x".into()),
+                                        pat: None,
+                                    }],
+                                }
+                            ),
+                            expr: id("c"),
+                        },
+                        vec![AST::SimpleExpr(id("x"))]
+                    )],
+                    else_body: Some(vec![AST::SimpleExpr(id("b"))]),
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::If(Box::new(If {
+                    condition: Condition::Expr(get_binary_op(
+                        "==",
+                        id("p"),
+                        SimpleExpr::Object(Object {
+                            name: "Point".into(),
+                            type_args: vec![],
+                            fields: vec![("x".into(), None)],
+                        })
+                    )),
+                    body: vec![AST::SimpleExpr(id("p"))],
+                    elifs: vec![],
+                    else_body: None,
+                }))
+            ))]))
+        );
+    }
+
+    #[test]
+    fn loop_expressions() {
+        // While with a pattern binding.
+        let combinator =
+            &mut get_combinator_for_code("while var [x, ...] = items {\n    x\n}".into());
+        let combinator_result_1 = parse!(combinator, f!(simple_expression));
+
+        // For with a range, several iteration arguments and a guard.
+        let combinator =
+            &mut get_combinator_for_code("for i in 0..n and j in list where i < j { i }".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        // Loop.
+        let combinator = &mut get_combinator_for_code("loop { x }".into());
+        let combinator_result_3 = parse!(combinator, f!(simple_expression));
+
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        // Creates an iteration argument.
+        let iter_arg = |name, expr| IterArg {
+            mutable: false,
+            pat: Pat::Ref(id(name)),
+            expr,
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::While(Box::new(While {
+                    condition: Condition::Binding {
+                        mutable: true,
+                        pat: Pat::Container(ContainerPat {
+                            kind: PatKind::List,
+                            args: vec![
+                                PatArg {
+                                    mutable: false,
+                                    rest: false,
+                                    name: Some("x".into()),
+                                    pat: None,
+                                },
+                                PatArg {
+                                    mutable: false,
+                                    rest: true,
+                                    name: None,
+                                    pat: None,
+                                }
+                            ],
+                        }),
+                        expr: id("items"),
+                    },
+                    body: vec![AST::SimpleExpr(id("x"))],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::For(Box::new(For {
+                    iter: Iter {
+                        args: vec![
+                            iter_arg(
+                                "i",
+                                SimpleExpr::Range(Range {
+                                    start: Some(Box::new(get_terminal(
+                                        TokenKind::IntegerDecimalLiteral,
+                                        "0"
+                                    ))),
+                                    step: None,
+                                    end: Some(Box::new(id("n"))),
+                                })
+                            ),
+                            iter_arg("j", id("list")),
+                        ],
+                        guard: Some(get_binary_op("<", id("i"), id("j"))),
+                    },
+                    body: vec![AST::SimpleExpr(id("i"))],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Loop(vec![AST::SimpleExpr(id("x"))])
+            ))]))
+        );
+    }
+
+    #[test]
+    fn control_flow_expression() {
+        // Values are optional and start on the same line as their keyword.
+        let combinator = &mut get_combinator_for_code(
+            "return\nyield from gen\nyield 1\nbreak x\ncontinue\nfallthrough".into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(expressions));
+
+        // Match arm body.
+        let combinator = &mut get_combinator_for_code("match x { 0 => return }".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::Block(vec![
+                AST::Expr(Expr::Return(None)),
+                AST::Expr(Expr::Yield(true, Some(id("gen")))),
+                AST::Expr(Expr::Yield(false, Some(int("1")))),
+                AST::Expr(Expr::Break(Some(id("x")))),
+                AST::Expr(Expr::Continue),
+                AST::Expr(Expr::Fallthrough),
+            ]))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Match(Box::new(Match {
+                    expr: id("x"),
+                    arms: vec![MatchArm {
+                        pat: MatchPat::Literal(int("0")),
+                        body: vec![AST::Expr(Expr::Return(None))],
+                    }],
+                }))
+            ))]))
+        );
+    }
+
+    #[test]
+    fn type_declaration() {
+        // Generic parameters, parents and fields.
+        let combinator = &mut get_combinator_for_code(
+            "pub type Buffer[T, U: Num | Str]: (Data, Sized) {\n    var data: List[T],\n    pub length: UInt = 0\n}"
+                .into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(type_declaration));
+
+        // Single parent and no body.
+        let combinator = &mut get_combinator_for_code("type Person: Human\ntype Empty".into());
+        let combinator_result_2 = parse!(combinator, f!(expressions));
+
+        // Generic parent.
+        let combinator = &mut get_combinator_for_code("type T[A, B] : (List[A]) { }".into());
+        let combinator_result_3 = parse!(combinator, f!(type_declaration));
+
+        let name = |value: &str| TypeExpr::Name(value.into());
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::TypeDecl(TypeDecl {
+                public: true,
+                name: "Buffer".into(),
+                generics: vec![
+                    GenericParam {
+                        name: "T".into(),
+                        bound: None,
+                    },
+                    GenericParam {
+                        name: "U".into(),
+                        bound: Some(TypeExpr::Union(vec![name("Num"), name("Str")])),
+                    },
+                ],
+                parents: vec![name("Data"), name("Sized")],
+                fields: vec![
+                    Field {
+                        public: false,
+                        mutable: true,
+                        name: "data".into(),
+                        ty: Some(TypeExpr::Generic(Box::new(name("List")), vec![name("T")])),
+                        default: None,
+                    },
+                    Field {
+                        public: true,
+                        mutable: false,
+                        name: "length".into(),
+                        ty: Some(name("UInt")),
+                        default: Some(AST::SimpleExpr(get_terminal(
+                            TokenKind::IntegerDecimalLiteral,
+                            "0"
+                        ))),
+                    },
+                ],
+            }))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::Block(vec![
+                AST::TypeDecl(TypeDecl {
+                    public: false,
+                    name: "Person".into(),
+                    generics: vec![],
+                    parents: vec![name("Human")],
+                    fields: vec![],
+                }),
+                AST::TypeDecl(TypeDecl {
+                    public: false,
+                    name: "Empty".into(),
+                    generics: vec![],
+                    parents: vec![],
+                    fields: vec![],
+                }),
+            ]))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::TypeDecl(TypeDecl {
+                public: false,
+                name: "T".into(),
+                generics: vec![
+                    GenericParam {
+                        name: "A".into(),
+                        bound: None,
+                    },
+                    GenericParam {
+                        name: "B".into(),
+                        bound: None,
+                    },
+                ],
+                parents: vec![TypeExpr::Generic(Box::new(name("List")), vec![name("A")])],
+                fields: vec![],
+            }))]))
+        );
+    }
+
+    #[test]
+    fn enum_declaration() {
+        // Unit, struct-like and tuple-like variants.
+        let combinator = &mut get_combinator_for_code(
+            "pub enum Payment[T] {\n    Cash,\n    Cheque { check_no: Int, memo },\n    Card(String, T, _),\n}"
+                .into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(enum_declaration));
+
+        // Block expression.
+        let combinator = &mut get_combinator_for_code("enum Fruits { Orange, Mango }".into());
+        let combinator_result_2 = parse!(combinator, f!(block_expression));
+
+        let name = |value: &str| TypeExpr::Name(value.into());
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::EnumDecl(EnumDecl {
+                public: true,
+                name: "Payment".into(),
+                generics: vec![GenericParam {
+                    name: "T".into(),
+                    bound: None,
+                }],
+                variants: vec![
+                    EnumVariant::Unit("Cash".into()),
+                    EnumVariant::Struct(
+                        "Cheque".into(),
+                        vec![
+                            ("check_no".into(), Some(name("Int"))),
+                            ("memo".into(), None),
+                        ]
+                    ),
+                    EnumVariant::Tuple(
+                        "Card".into(),
+                        vec![Some(name("String")), Some(name("T")), None]
+                    ),
+                ],
+            }))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::EnumDecl(EnumDecl {
+                public: false,
+                name: "Fruits".into(),
+                generics: vec![],
+                variants: vec![
+                    EnumVariant::Unit("Orange".into()),
+                    EnumVariant::Unit("Mango".into()),
+                ],
+            }))]))
+        );
+    }
+
+    #[test]
+    fn function_and_closure() {
+        // Operator name, generic parameters, every kind of parameter and a return type.
+        let combinator = &mut get_combinator_for_code(
+            "pub fun +[T](x: Dual, { re, im }, scale? = 1) -> Dual {\n    x\n}".into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(simple_expression));
+
+        // Anonymous function without parameters or body.
+        let combinator = &mut get_combinator_for_code("fun () {}".into());
+        let combinator_result_2 = parse!(combinator, f!(simple_expression));
+
+        // Closures with an expression and a block.
+        let combinator =
+            &mut get_combinator_for_code("(x, y) => x + y\n() => {\n    return\n}".into());
+        let combinator_result_3 = parse!(combinator, f!(expressions));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+        let name = |value: &str| TypeExpr::Name(value.into());
+
+        // Creates a named parameter.
+        let param = |name: &str, ty, default, optional| Param::Named {
+            name: name.into(),
+            ty,
+            default,
+            optional,
+        };
+
+        // Creates a pattern argument.
+        let arg = |name: &str| PatArg {
+            mutable: false,
+            rest: false,
+            name: Some(name.into()),
+            pat: None,
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Function(Box::new(FunctionDecl {
+                    public: true,
+                    name: Some("+".into()),
+                    generics: vec![GenericParam {
+                        name: "T".into(),
+                        bound: None,
+                    }],
+                    params: vec![
+                        param("x", Some(name("Dual")), None, false),
+                        Param::Pat(ContainerPat {
+                            kind: PatKind::Dict,
+                            args: vec![arg("re"), arg("im")],
+                        }),
+                        param("scale", None, Some(AST::SimpleExpr(int("1"))), true),
+                    ],
+                    return_type: Some(name("Dual")),
+                    body: vec![AST::SimpleExpr(id("x"))],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::SimpleExpr(
+                SimpleExpr::Function(Box::new(FunctionDecl {
+                    public: false,
+                    name: None,
+                    generics: vec![],
+                    params: vec![],
+                    return_type: None,
+                    body: vec![],
+                }))
+            ))]))
+        );
+        assert_eq!(
+            combinator_result_3,
+            Ok(Output::Values(vec![Output::AST(AST::Block(vec![
+                AST::SimpleExpr(SimpleExpr::Closure(Box::new(Closure {
+                    params: vec![param("x", None, None, false), param("y", None, None, false)],
+                    body: vec![AST::SimpleExpr(get_binary_op("+", id("x"), id("y")))],
+                }))),
+                AST::SimpleExpr(SimpleExpr::Closure(Box::new(Closure {
+                    params: vec![],
+                    body: vec![AST::Expr(Expr::Return(None))],
+                }))),
+            ]))]))
+        );
+    }
+
+    #[test]
+    fn subject_and_assignment() {
+        // Bindings with patterns, types and values.
+        let combinator = &mut get_combinator_for_code(
+            "pub const (a, b): (Int, Int) = t\nvar x\nlet y = 1".into(),
+        );
+        let combinator_result_1 = parse!(combinator, f!(expressions));
+
+        // Assignments with compound operators.
+        let combinator = &mut get_combinator_for_code("xs[0] += 1\nx .= y\nz: Int = 2".into());
+        let combinator_result_2 = parse!(combinator, f!(expressions));
+
+        let int = |value| get_terminal(TokenKind::IntegerDecimalLiteral, value);
+        let id = |value| get_terminal(TokenKind::Identifier, value);
+        let name = |value: &str| TypeExpr::Name(value.into());
+
+        // Creates a pattern argument.
+        let arg = |name: &str| PatArg {
+            mutable: false,
+            rest: false,
+            name: Some(name.into()),
+            pat: None,
+        };
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::Block(vec![
+                AST::Expr(Expr::Binding(Binding {
+                    public: true,
+                    kind: BindingKind::Const,
+                    pat: Pat::Container(ContainerPat {
+                        kind: PatKind::Tuple,
+                        args: vec![arg("a"), arg("b")],
+                    }),
+                    ty: Some(TypeExpr::Tuple(vec![name("Int"), name("Int")])),
+                    value: Some(Box::new(AST::SimpleExpr(id("t")))),
+                })),
+                AST::Expr(Expr::Binding(Binding {
+                    public: false,
+                    kind: BindingKind::Var,
+                    pat: Pat::Ref(id("x")),
+                    ty: None,
+                    value: None,
+                })),
+                AST::Expr(Expr::Binding(Binding {
+                    public: false,
+                    kind: BindingKind::Let,
+                    pat: Pat::Ref(id("y")),
+                    ty: None,
+                    value: Some(Box::new(AST::SimpleExpr(int("1")))),
+                })),
+            ]))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::Block(vec![
+                AST::Expr(Expr::Assign(Assign {
+                    pat: Pat::Ref(SimpleExpr::Index {
+                        expr: Box::new(id("xs")),
+                        args: vec![int("0")],
+                    }),
+                    ty: None,
+                    op: "+=".into(),
+                    value: Box::new(AST::SimpleExpr(int("1"))),
+                })),
+                AST::Expr(Expr::Assign(Assign {
+                    pat: Pat::Ref(id("x")),
+                    ty: None,
+                    op: ".=".into(),
+                    value: Box::new(AST::SimpleExpr(id("y"))),
+                })),
+                AST::Expr(Expr::Assign(Assign {
+                    pat: Pat::Ref(id("z")),
+                    ty: Some(name("Int")),
+                    op: "=".into(),
+                    value: Box::new(AST::SimpleExpr(int("2"))),
+                })),
+            ]))]))
+        );
+    }
+
+    #[test]
+    fn program() {
+        // Empty program.
+        let combinator = &mut get_combinator_for_code("\n\n".into());
+        let combinator_result_1 = parse!(combinator, f!(program));
+
+        // Expressions separated by newlines and semicolons.
+        let combinator = &mut get_combinator_for_code("\n5\r\n(_, 1,); name;\n".into());
+        let combinator_result_2 = parse!(combinator, f!(program));
+
+        assert_eq!(
+            combinator_result_1,
+            Ok(Output::Values(vec![Output::AST(AST::Program(vec![]))]))
+        );
+        assert_eq!(
+            combinator_result_2,
+            Ok(Output::Values(vec![Output::AST(AST::Program(vec![
+                AST::SimpleExpr(SimpleExpr::Terminal {
+                    kind: TokenKind::IntegerDecimalLiteral,
+                    value: "5".into()
+                }),
+                AST::SimpleExpr(SimpleExpr::Tuple(vec![
+                    SimpleExpr::Terminal {
+                        kind: TokenKind::Placeholder,
+                        value: "_".into()
+                    },
+                    SimpleExpr::Terminal {
+                        kind: TokenKind::IntegerDecimalLiteral,
+                        value: "1".into()
+                    }
+                ])),
+                AST::SimpleExpr(SimpleExpr::Terminal {
+                    kind: TokenKind::Identifier,
+                    value: "name".into()
+                })
+            ]))]))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        // Complete input.
+        let tokens = Lexer::new("[1, (2)]".into()).lex().unwrap();
+        let parser_result_1 = Parser::new(tokens).parse();
+
+        // Unconsumed tokens.
+        let tokens = Lexer::new("[1, 2] 3".into()).lex().unwrap();
+        let parser_result_2 = Parser::new(tokens).parse();
+
+        assert_eq!(
+            parser_result_1,
+            Ok(AST::Program(vec![AST::SimpleExpr(SimpleExpr::List(vec![
+                SimpleExpr::Terminal {
+                    kind: TokenKind::IntegerDecimalLiteral,
+                    value: "1".into()
+                },
+                SimpleExpr::Terminal {
+                    kind: TokenKind::IntegerDecimalLiteral,
+                    value: "2".into()
+                }
+            ]))]))
+        );
+        assert_eq!(
+            parser_result_2,
+            Err(ParserError {
+                error: ErrorKind::IncompleteParse,
+                span: Span::new(7, 8, 1, 8),
+                expected: vec![
+                    "postfix".into(),
+                    "operator".into(),
+                    "`..`".into(),
+                    "`;`".into(),
+                    "newline".into()
+                ],
+                found: Some("`3`".into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_recovering() {
+        // Errors on multiple lines.
+        let tokens = Lexer::new("1 2\n[3, 4 5]\n(6, 7); [8, (9]\n10".into())
+            .lex()
+            .unwrap();
+        let (ast, errors) = Parser::new(tokens).parse_recovering();
+
+        assert_eq!(
+            ast,
+            AST::Program(vec![
+                AST::Error(Span::new(0, 3, 1, 1)),
+                AST::Error(Span::new(4, 12, 2, 1)),
+                AST::SimpleExpr(SimpleExpr::Tuple(vec![
+                    SimpleExpr::Terminal {
+                        kind: TokenKind::IntegerDecimalLiteral,
+                        value: "6".into()
+                    },
+                    SimpleExpr::Terminal {
+                        kind: TokenKind::IntegerDecimalLiteral,
+                        value: "7".into()
+                    }
+                ])),
+                AST::Error(Span::new(21, 28, 3, 9)),
+                AST::SimpleExpr(SimpleExpr::Terminal {
+                    kind: TokenKind::IntegerDecimalLiteral,
+                    value: "10".into()
+                }),
+            ])
+        );
+        assert_eq!(
+            errors,
+            vec![
+                ParserError {
+                    error: ErrorKind::UnexpectedToken,
+                    span: Span::new(2, 3, 1, 3),
+                    expected: vec![
+                        "postfix".into(),
+                        "operator".into(),
+                        "`..`".into(),
+                        "newline".into(),
+                        "`;`".into()
+                    ],
+                    found: Some("`2`".into())
+                },
+                ParserError {
+                    error: ErrorKind::ExpectedBlockExpression,
+                    span: Span::new(10, 11, 2, 7),
+                    expected: vec![
+                        "postfix".into(),
+                        "operator".into(),
+                        "`..`".into(),
+                        "newline".into(),
+                        "`,`".into(),
+                        "`]`".into()
+                    ],
+                    found: Some("`5`".into())
+                },
+                ParserError {
+                    error: ErrorKind::ExpectedBlockExpression,
+                    span: Span::new(27, 28, 3, 15),
+                    expected: vec![
+                        "identifier".into(),
+                        "`(`".into(),
+                        "postfix".into(),
+                        "operator".into(),
+                        "`..`".into(),
+                        "newline".into(),
+                        "`)`".into(),
+                        "`|`".into(),
+                        "`,`".into()
+                    ],
+                    found: Some("`]`".into())
+                },
+            ]
+        );
+
+        // Statements that fail are skipped as a whole.
+        let tokens = Lexer::new("let x = 1 +\nlet y = 2\nlet a = )".into())
+            .lex()
+            .unwrap();
+        let (ast, errors) = Parser::new(tokens).parse_recovering();
+
+        assert_eq!(
+            ast,
+            AST::Program(vec![
+                AST::Error(Span::new(0, 11, 1, 1)),
+                AST::Expr(Expr::Binding(Binding {
+                    public: false,
+                    kind: BindingKind::Let,
+                    pat: Pat::Ref(get_terminal(TokenKind::Identifier, "y")),
+                    ty: None,
+                    value: Some(Box::new(AST::SimpleExpr(get_terminal(
+                        TokenKind::IntegerDecimalLiteral,
+                        "2"
+                    )))),
+                })),
+                AST::Error(Span::new(22, 31, 3, 1)),
+            ])
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.error.clone(), error.span, error.found.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ErrorKind::UnexpectedToken,
+                    Span::new(11, 12, 1, 12),
+                    Some("newline".into())
+                ),
+                (
+                    ErrorKind::UnexpectedToken,
+                    Span::new(30, 31, 3, 9),
+                    Some("`)`".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_recovering_in_blocks() {
+        // Errors in a function body.
+        let tokens = Lexer::new("fun f() {\n    a )\n    let = 1\n    b\n}\nc".into())
+            .lex()
+            .unwrap();
+        let (ast, errors) = Parser::new(tokens).parse_recovering();
+
+        assert_eq!(
+            ast,
+            AST::Program(vec![
+                AST::SimpleExpr(SimpleExpr::Function(Box::new(FunctionDecl {
+                    public: false,
+                    name: Some("f".into()),
+                    generics: vec![],
+                    params: vec![],
+                    return_type: None,
+                    body: vec![
+                        AST::Error(Span::new(14, 17, 2, 5)),
+                        AST::Error(Span::new(22, 29, 3, 5)),
+                        AST::SimpleExpr(SimpleExpr::Terminal {
+                            kind: TokenKind::Identifier,
+                            value: "b".into()
+                        }),
+                    ]
+                }))),
+                AST::SimpleExpr(SimpleExpr::Terminal {
+                    kind: TokenKind::Identifier,
+                    value: "c".into()
+                }),
+            ])
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.error.clone(), error.span, error.found.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ErrorKind::UnexpectedToken,
+                    Span::new(16, 17, 2, 7),
+                    Some("`)`".into())
+                ),
+                (
+                    ErrorKind::ExpectedBlockExpression,
+                    Span::new(26, 27, 3, 9),
+                    Some("`=`".into())
+                ),
+            ]
+        );
+
+        // Errors in a type body.
+        let tokens = Lexer::new("type T {\n    a: Int,\n    b: ),\n    c: Int\n}".into())
+            .lex()
+            .unwrap();
+        let (ast, errors) = Parser::new(tokens).parse_recovering();
+
+        assert_eq!(
+            ast,
+            AST::Program(vec![AST::TypeDecl(TypeDecl {
+                public: false,
+                name: "T".into(),
+                generics: vec![],
+                parents: vec![],
+                fields: vec![
+                    Field {
+                        public: false,
+                        mutable: false,
+                        name: "a".into(),
+                        ty: Some(TypeExpr::Name("Int".into())),
+                        default: None
+                    },
+                    Field {
+                        public: false,
+                        mutable: false,
+                        name: "c".into(),
+                        ty: Some(TypeExpr::Name("Int".into())),
+                        default: None
+                    },
+                ]
+            })])
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.error.clone(), error.span, error.found.clone()))
+                .collect::<Vec<_>>(),
+            vec![(
+                ErrorKind::UnexpectedToken,
+                Span::new(28, 29, 3, 8),
+                Some("`)`".into())
+            )]
+        );
+
+        // Fields that fail are left out.
+        let tokens = Lexer::new("type T { a: Int, b: , c: Int }".into())
+            .lex()
+            .unwrap();
+        let (ast, errors) = Parser::new(tokens).parse_recovering();
+
+        assert_eq!(
+            ast,
+            AST::Program(vec![AST::TypeDecl(TypeDecl {
+                public: false,
+                name: "T".into(),
+                generics: vec![],
+                parents: vec![],
+                fields: vec![
+                    Field {
+                        public: false,
+                        mutable: false,
+                        name: "a".into(),
+                        ty: Some(TypeExpr::Name("Int".into())),
+                        default: None
+                    },
+                    Field {
+                        public: false,
+                        mutable: false,
+                        name: "c".into(),
+                        ty: Some(TypeExpr::Name("Int".into())),
+                        default: None
+                    },
+                ]
+            })])
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.error.clone(), error.span, error.found.clone()))
+                .collect::<Vec<_>>(),
+            vec![(
+                ErrorKind::UnexpectedToken,
+                Span::new(20, 21, 1, 21),
+                Some("`,`".into())
+            )]
+        );
+    }
+
+    #[test]
+    fn expected_tokens() {
+        // Alternatives fail at the same token.
+        let combinator = &mut get_combinator_for_code("[1, 2)".into());
+        let combinator_result_1 = alt!(combinator, f!(list_literal), f!(tuple_literal));
+
+        // Input ends early.
+        let combinator = &mut get_combinator_for_code("(1, 2".into());
+        let combinator_result_2 = alt!(combinator, f!(list_literal), f!(tuple_literal));
+
+        assert_eq!(
+            combinator_result_1,
+            Err(ParserError {
+                error: ErrorKind::AlternativesDontMatch,
+                span: Span::new(5, 6, 1, 6),
+                expected: vec![
+                    "identifier".into(),
+                    "`(`".into(),
+                    "postfix".into(),
+                    "operator".into(),
+                    "`..`".into(),
+                    "newline".into(),
+                    "`,`".into(),
+                    "`]`".into()
+                ],
+                found: Some("`)`".into())
+            })
+        );
+        assert_eq!(
+            combinator_result_1.unwrap_err().to_string(),
+            "1:6: expected identifier, `(`, postfix, operator, `..`, newline, `,` or `]`, found `)`"
+        );
+        assert_eq!(
+            combinator_result_2.unwrap_err().to_string(),
+            "1:6: expected postfix, operator, `..`, newline, `,` or `)`, found end of input"
+        );
+    }
 }